@@ -0,0 +1,217 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use aoc_core::Part;
+
+use crate::{ClientError, Verdict};
+
+const INPUT_FILE: &str = "input.txt";
+const SUBMISSIONS_FILE: &str = "submissions.toml";
+const THROTTLE_FILE: &str = "throttle.toml";
+
+// an answer sent for one part and the site's final verdict on it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Submissions {
+    #[serde(rename = "submission", default)]
+    submissions: Vec<Submission>,
+}
+
+// when answers were last sent, as seconds since the unix epoch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Throttle {
+    pub last_submission: Option<u64>,
+    // set when the site asked to wait longer than the usual interval
+    pub wait_until: Option<u64>,
+}
+
+impl Throttle {
+    // how long to wait at now before the next submission, if at all
+    pub fn wait(&self, now: u64, interval: Duration) -> Option<Duration> {
+        let after_last = self
+            .last_submission
+            .map(|last| last.saturating_add(interval.as_secs()));
+        let next = after_last.max(self.wait_until)?;
+        (now < next).then(|| Duration::from_secs(next - now))
+    }
+}
+
+// fetched inputs and past submissions on disk, one directory per day, plus the throttle state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+fn read<T: DeserializeOwned + Default>(path: &Path) -> Result<T, ClientError> {
+    match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).map_err(|source| ClientError::Parse {
+            path: path.to_path_buf(),
+            source,
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(source) => Err(ClientError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ClientError> {
+    let io_error = |source| ClientError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+impl Cache {
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{}", day))
+    }
+
+    pub fn input(&self, day: u8) -> Result<Option<String>, ClientError> {
+        let path = self.day_dir(day).join(INPUT_FILE);
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(ClientError::Io { path, source }),
+        }
+    }
+
+    pub fn save_input(&self, day: u8, input: &str) -> Result<(), ClientError> {
+        write(&self.day_dir(day).join(INPUT_FILE), input)
+    }
+
+    pub fn submissions(&self, day: u8) -> Result<Vec<Submission>, ClientError> {
+        let submissions: Submissions = read(&self.day_dir(day).join(SUBMISSIONS_FILE))?;
+        Ok(submissions.submissions)
+    }
+
+    // the earlier submission of this answer, or the correct one for the part
+    pub fn find_submission(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Option<Submission>, ClientError> {
+        let submissions = self.submissions(day)?;
+        let for_part = || submissions.iter().filter(|s| s.part == part.number());
+        Ok(for_part()
+            .find(|s| s.answer == answer)
+            .or_else(|| for_part().find(|s| s.verdict == Verdict::Correct))
+            .cloned())
+    }
+
+    pub fn record_submission(&self, day: u8, submission: Submission) -> Result<(), ClientError> {
+        let mut submissions = self.submissions(day)?;
+        submissions.push(submission);
+        let text =
+            toml::to_string(&Submissions { submissions }).expect("submissions always serialize");
+        write(&self.day_dir(day).join(SUBMISSIONS_FILE), &text)
+    }
+
+    pub fn throttle(&self) -> Result<Throttle, ClientError> {
+        read(&self.dir.join(THROTTLE_FILE))
+    }
+
+    pub fn save_throttle(&self, throttle: &Throttle) -> Result<(), ClientError> {
+        let text = toml::to_string(throttle).expect("the throttle always serializes");
+        write(&self.dir.join(THROTTLE_FILE), &text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(&dir.path().join("cache"));
+        assert_eq!(cache.input(3).unwrap(), None);
+        cache.save_input(3, "mul(2,4)\n").unwrap();
+        assert_eq!(cache.input(3).unwrap().as_deref(), Some("mul(2,4)\n"));
+        assert!(dir.path().join("cache/day3/input.txt").is_file());
+    }
+
+    #[test]
+    fn test_submissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        let wrong = Submission {
+            part: 1,
+            answer: "12".to_string(),
+            verdict: Verdict::Incorrect {
+                hint: Some("too low".to_string()),
+            },
+        };
+        let right = Submission {
+            part: 1,
+            answer: "13".to_string(),
+            verdict: Verdict::Correct,
+        };
+        cache.record_submission(2, wrong.clone()).unwrap();
+        cache.record_submission(2, right.clone()).unwrap();
+        assert_eq!(
+            cache.submissions(2).unwrap(),
+            vec![wrong.clone(), right.clone()]
+        );
+        assert_eq!(cache.submissions(1).unwrap(), vec![]);
+
+        let find = |part, answer| cache.find_submission(2, part, answer).unwrap();
+        assert_eq!(find(Part::One, "12"), Some(wrong));
+        assert_eq!(find(Part::One, "14"), Some(right));
+        assert_eq!(find(Part::Two, "12"), None);
+
+        let text = fs::read_to_string(dir.path().join("day2/submissions.toml")).unwrap();
+        assert!(text.contains("verdict = \"incorrect\""), "{}", text);
+        assert!(text.contains("hint = \"too low\""), "{}", text);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+        let minute = Duration::from_secs(60);
+        assert_eq!(cache.throttle().unwrap(), Throttle::default());
+        assert_eq!(Throttle::default().wait(1000, minute), None);
+
+        let throttle = Throttle {
+            last_submission: Some(1000),
+            wait_until: None,
+        };
+        assert_eq!(throttle.wait(1010, minute), Some(Duration::from_secs(50)));
+        assert_eq!(throttle.wait(1060, minute), None);
+        let told_to_wait = Throttle {
+            wait_until: Some(1300),
+            ..throttle
+        };
+        assert_eq!(
+            told_to_wait.wait(1100, minute),
+            Some(Duration::from_secs(200))
+        );
+
+        cache.save_throttle(&told_to_wait).unwrap();
+        assert_eq!(cache.throttle().unwrap(), told_to_wait);
+    }
+}
@@ -0,0 +1,362 @@
+use std::env;
+use std::fmt::{self, Debug, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+use aoc_core::Part;
+
+mod cache;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+mod verdict;
+
+pub use cache::{Cache, Submission, Throttle};
+pub use verdict::Verdict;
+
+pub const YEAR: u16 = 2024;
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+// session cookie file looked up in the given directory and each of its ancestors
+pub const SESSION_FILE: &str = ".aoc-session";
+// session cookie used when no session file is found
+pub const SESSION_ENV: &str = "AOC_SESSION";
+// the site asks for at least a minute between answers
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(60);
+
+// the site asks automated tools to say who they are
+const USER_AGENT: &str = concat!("aoc-2024-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error(
+        "no session cookie found: save it in {} or set {}",
+        SESSION_FILE,
+        SESSION_ENV
+    )]
+    MissingSession,
+    #[error("{url}: {message}")]
+    Http { url: String, message: String },
+    #[error("{url}: HTTP {status}: {body}")]
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    #[error("too soon to submit another answer, wait {}s", .0.as_secs())]
+    Throttled(Duration),
+    #[error("unexpected reply to the answer: {0}")]
+    UnexpectedResponse(String),
+}
+
+// the value of the site's session cookie
+#[derive(Clone, PartialEq, Eq)]
+pub struct Session(String);
+
+impl Session {
+    pub fn new(cookie: &str) -> Self {
+        Session(cookie.trim().to_string())
+    }
+
+    // the nearest session file at or above dir, then the cookie in the environment
+    pub fn find(dir: &Path) -> Result<Self, ClientError> {
+        Session::find_with(dir, env::var(SESSION_ENV).ok())
+    }
+
+    fn find_with(dir: &Path, env_session: Option<String>) -> Result<Self, ClientError> {
+        let file = dir
+            .ancestors()
+            .map(|dir| dir.join(SESSION_FILE))
+            .find(|path| path.is_file());
+        if let Some(path) = file {
+            let text = fs::read_to_string(&path).map_err(|source| ClientError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            return Ok(Session::new(&text));
+        }
+        env_session
+            .map(|cookie| Session::new(&cookie))
+            .ok_or(ClientError::MissingSession)
+    }
+}
+
+// never print the cookie itself
+impl Debug for Session {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Session(..)")
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+// fetches inputs and submits answers, answering from the cache whenever it can
+#[derive(Debug)]
+pub struct Client {
+    url: String,
+    session: Session,
+    cache: Cache,
+    throttle: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    // url is the site's root, e.g. DEFAULT_URL
+    pub fn new(url: &str, session: Session, cache: Cache) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            session,
+            cache,
+            throttle: DEFAULT_THROTTLE,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    // the least time between two submissions
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    fn day_url(&self, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.url, YEAR, day, page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session.0)
+    }
+
+    fn body(
+        url: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        let http_error = |e: &dyn std::error::Error| ClientError::Http {
+            url: url.to_string(),
+            message: e.to_string(),
+        };
+        match response {
+            Ok(response) => response.into_string().map_err(|e| http_error(&e)),
+            Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+                url: url.to_string(),
+                status,
+                body: response
+                    .into_string()
+                    .unwrap_or_default()
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+            }),
+            Err(e) => Err(http_error(&e)),
+        }
+    }
+
+    // the day's puzzle input, downloaded only the first time
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        if let Some(input) = self.cache.input(day)? {
+            return Ok(input);
+        }
+        let url = self.day_url(day, "input");
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+        let input = Client::body(&url, response)?;
+        self.cache.save_input(day, &input)?;
+        Ok(input)
+    }
+
+    // send an answer, unless it or the part's correct answer was sent before, keeping at least
+    // the throttle interval and any wait the site asked for between submissions
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        match self.cache.find_submission(day, part, answer)? {
+            Some(earlier) if earlier.answer == answer => return Ok(earlier.verdict),
+            Some(_) => return Ok(Verdict::AlreadySolved),
+            None => {}
+        }
+        let mut throttle = self.cache.throttle()?;
+        let sent_at = now();
+        if let Some(wait) = throttle.wait(sent_at, self.throttle) {
+            return Err(ClientError::Throttled(wait));
+        }
+
+        let url = self.day_url(day, "answer");
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.number().to_string()), ("answer", answer)]);
+        throttle.last_submission = Some(sent_at);
+        self.cache.save_throttle(&throttle)?;
+        let verdict = Verdict::from_response(&Client::body(&url, response)?)?;
+
+        if let Some(wait) = verdict.wait() {
+            throttle.wait_until = Some(sent_at + wait.as_secs());
+            self.cache.save_throttle(&throttle)?;
+        }
+        if verdict.is_final() {
+            self.cache.record_submission(
+                day,
+                Submission {
+                    part: part.number(),
+                    answer: answer.to_string(),
+                    verdict: verdict.clone(),
+                },
+            )?;
+        }
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    const SESSION: &str = "53616c7465645f5f";
+
+    fn client(server: &MockServer, cache_dir: &Path) -> Client {
+        Client::new(&server.url(), Session::new(SESSION), Cache::new(cache_dir))
+            .with_throttle(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_find_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("input");
+        fs::create_dir(&nested).unwrap();
+        assert!(matches!(
+            Session::find_with(&nested, None),
+            Err(ClientError::MissingSession)
+        ));
+        assert_eq!(
+            Session::find_with(&nested, Some("env".to_string())).unwrap(),
+            Session::new("env")
+        );
+        fs::write(dir.path().join(SESSION_FILE), "file\n").unwrap();
+        assert_eq!(
+            Session::find_with(&nested, Some("env".to_string())).unwrap(),
+            Session::new("file")
+        );
+        assert_eq!(format!("{:?}", Session::new("secret")), "Session(..)");
+    }
+
+    #[test]
+    fn test_input_is_fetched_once() {
+        let server = MockServer::start(SESSION);
+        server.add_day(1, "3   4\n4   3\n", ["1", "2"]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = client(&server, dir.path());
+        assert_eq!(client.input(1).unwrap(), "3   4\n4   3\n");
+        assert_eq!(client.input(1).unwrap(), "3   4\n4   3\n");
+        assert_eq!(server.requests(), vec!["GET /2024/day/1/input"]);
+        assert!(matches!(
+            client.input(2),
+            Err(ClientError::Status { status: 404, .. })
+        ));
+    }
+
+    #[test]
+    fn test_wrong_session() {
+        let server = MockServer::start(SESSION);
+        server.add_day(1, "1   1\n", ["0", "1"]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = Client::new(&server.url(), Session::new("other"), Cache::new(dir.path()));
+        assert!(matches!(
+            client.input(1),
+            Err(ClientError::Status { status: 400, .. })
+        ));
+        assert_eq!(client.cache().input(1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(SESSION);
+        server.add_day(2, "1 2 3\n", ["341", "404"]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = client(&server, dir.path());
+
+        assert_eq!(
+            client.submit(2, Part::One, "400").unwrap(),
+            Verdict::Incorrect {
+                hint: Some("too high".to_string())
+            }
+        );
+        assert_eq!(
+            client.submit(2, Part::One, "341").unwrap(),
+            Verdict::Correct
+        );
+        // both answers are known now and are not sent again
+        assert_eq!(
+            client.submit(2, Part::One, "400").unwrap(),
+            Verdict::Incorrect {
+                hint: Some("too high".to_string())
+            }
+        );
+        assert_eq!(
+            client.submit(2, Part::One, "341").unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            client.submit(2, Part::One, "342").unwrap(),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(client.cache().submissions(2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_submit_throttled() {
+        let server = MockServer::start(SESSION);
+        server.add_day(3, "mul(2,4)\n", ["8", "8"]);
+        let dir = tempfile::tempdir().unwrap();
+        let client = client(&server, dir.path()).with_throttle(Duration::from_secs(3600));
+
+        assert!(matches!(
+            client.submit(3, Part::One, "7").unwrap(),
+            Verdict::Incorrect { .. }
+        ));
+        match client.submit(3, Part::One, "8") {
+            Err(ClientError::Throttled(wait)) => assert!(wait > Duration::from_secs(3500)),
+            other => panic!("expected to be throttled, got {:?}", other),
+        }
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_submit_too_soon() {
+        let server = MockServer::start(SESSION);
+        server.add_day(4, "XMAS\n", ["1", "0"]);
+        server.set_cooldown(Duration::from_secs(90));
+        let dir = tempfile::tempdir().unwrap();
+        let client = client(&server, dir.path());
+
+        assert!(client.submit(4, Part::One, "2").unwrap().is_final());
+        // the site refuses the next answer, and the client then waits as long as it was told
+        let verdict = client.submit(4, Part::One, "1").unwrap();
+        assert!(matches!(verdict, Verdict::TooSoon { seconds } if seconds > 80));
+        assert!(matches!(
+            client.submit(4, Part::One, "1"),
+            Err(ClientError::Throttled(_))
+        ));
+        assert_eq!(server.requests().len(), 2);
+        assert!(client.cache().throttle().unwrap().wait_until.is_some());
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::YEAR;
+
+// a day the mock site serves: its input, the answers to both parts and which are solved
+struct Day {
+    input: String,
+    answers: [String; 2],
+    solved: [bool; 2],
+}
+
+#[derive(Default)]
+struct Site {
+    days: HashMap<u8, Day>,
+    // wait imposed after a wrong answer, like the site's
+    cooldown: Duration,
+    cooling_until: Option<Instant>,
+    requests: Vec<String>,
+}
+
+// the puzzle site on a local port, replying like the real one, for tests that must not touch
+// the network; stops when dropped
+pub struct MockServer {
+    url: String,
+    server: Arc<Server>,
+    site: Arc<Mutex<Site>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+// the site's reply pages keep the message in an <article>
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\"><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        message
+    )
+}
+
+// a form value: + is a space and %XX a byte
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = String::from_utf8_lossy(&bytes[i + 1..i + 3]);
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (decode(key), decode(value)))
+        .collect()
+}
+
+impl Site {
+    // the status and body of the reply to one request
+    fn reply(&mut self, method: &Method, path: &str, body: &str, signed_in: bool) -> (u16, String) {
+        self.requests.push(format!("{} {}", method, path));
+        if !signed_in {
+            return (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            );
+        }
+        let prefix = format!("/{}/day/", YEAR);
+        let Some((day, page_name)) = path
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.split_once('/'))
+        else {
+            return (404, "404 Not Found\n".to_string());
+        };
+        let Some(day) = day.parse().ok().and_then(|day: u8| self.days.get_mut(&day)) else {
+            return (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!\n".to_string(),
+            );
+        };
+        match (method, page_name) {
+            (Method::Get, "input") => (200, day.input.clone()),
+            (Method::Post, "answer") => {
+                let form = form(body);
+                let level = form
+                    .get("level")
+                    .and_then(|level| level.parse::<usize>().ok());
+                let (Some(level @ 1..=2), Some(answer)) = (level, form.get("answer")) else {
+                    return (400, "Bad Request\n".to_string());
+                };
+                let now = Instant::now();
+                if let Some(left) = self
+                    .cooling_until
+                    .and_then(|until| until.checked_duration_since(now))
+                {
+                    let message = format!(
+                        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {}s left to wait.",
+                        left.as_secs().max(1)
+                    );
+                    return (200, page(&message));
+                }
+                if day.solved[level - 1] {
+                    return (
+                        200,
+                        page("You don't seem to be solving the right level.  Did you already complete it?"),
+                    );
+                }
+                let expected = &day.answers[level - 1];
+                if answer == expected {
+                    day.solved[level - 1] = true;
+                    return (200, page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian."));
+                }
+                self.cooling_until = Some(now + self.cooldown);
+                let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+                    (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+                    (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+                    _ => "",
+                };
+                let message = format!(
+                    "That's not the right answer{}.  Please wait one minute before trying again.",
+                    hint
+                );
+                (200, page(&message))
+            }
+            _ => (404, "404 Not Found\n".to_string()),
+        }
+    }
+}
+
+fn respond(site: &Mutex<Site>, session: &str, mut request: Request) {
+    let cookie = format!("session={}", session);
+    let signed_in = request.headers().iter().any(|header| {
+        header.field.equiv("Cookie")
+            && header
+                .value
+                .as_str()
+                .split(';')
+                .any(|pair| pair.trim() == cookie)
+    });
+    let mut body = String::new();
+    let _ = request.as_reader().read_to_string(&mut body);
+    let method = request.method().clone();
+    let path = request.url().to_string();
+    let (status, text) = site
+        .lock()
+        .expect("the mock site is never poisoned")
+        .reply(&method, &path, &body, signed_in);
+    let content_type = Header::from_bytes("Content-Type", "text/html; charset=utf-8")
+        .expect("the header is valid");
+    let response = Response::from_string(text)
+        .with_status_code(status)
+        .with_header(content_type);
+    let _ = request.respond(response);
+}
+
+impl MockServer {
+    // serve on a free local port, accepting requests that carry this session cookie
+    pub fn start(session: &str) -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("a local port is free"));
+        let address = server
+            .server_addr()
+            .to_ip()
+            .expect("the mock server listens on an IP address");
+        let site = Arc::new(Mutex::new(Site::default()));
+        let handle = {
+            let server = Arc::clone(&server);
+            let site = Arc::clone(&site);
+            let session = session.to_string();
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(&site, &session, request);
+                }
+            })
+        };
+        Self {
+            url: format!("http://{}", address),
+            server,
+            site,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn site(&self) -> std::sync::MutexGuard<'_, Site> {
+        self.site.lock().expect("the mock site is never poisoned")
+    }
+
+    // unlock a day with its input and the answers to its two parts
+    pub fn add_day(&self, day: u8, input: &str, answers: [&str; 2]) {
+        self.site().days.insert(
+            day,
+            Day {
+                input: input.to_string(),
+                answers: answers.map(str::to_string),
+                solved: [false; 2],
+            },
+        );
+    }
+
+    // how long a wrong answer locks out the next one, none by default
+    pub fn set_cooldown(&self, cooldown: Duration) {
+        self.site().cooldown = cooldown;
+    }
+
+    // every request so far, as "GET /2024/day/1/input"
+    pub fn requests(&self) -> Vec<String> {
+        self.site().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("a+b%2Cc"), "a b,c");
+        assert_eq!(decode("100%"), "100%");
+        assert_eq!(decode("%zz"), "%zz");
+    }
+
+    #[test]
+    fn test_form() {
+        let form = form("level=2&answer=1%2B1");
+        assert_eq!(form["level"], "2");
+        assert_eq!(form["answer"], "1+1");
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ClientError;
+
+// the site's reply to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    // hint is "too high" or "too low" when the site gives one
+    Incorrect {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
+    },
+    // the answer was not checked, the site wants this many seconds before the next one
+    TooSoon {
+        seconds: u64,
+    },
+    // the part was already solved, so the answer was not checked
+    AlreadySolved,
+}
+
+impl Verdict {
+    // parse the HTML page the site answers a submission with
+    pub fn from_response(html: &str) -> Result<Self, ClientError> {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| text.contains(&format!("your answer is {}", hint)))
+                .map(str::to_string);
+            Ok(Verdict::Incorrect { hint })
+        } else if text.contains("You gave an answer too recently") {
+            Ok(Verdict::TooSoon {
+                seconds: wait_seconds(&text).unwrap_or(60),
+            })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::AlreadySolved)
+        } else {
+            Err(ClientError::UnexpectedResponse(
+                text.chars().take(200).collect(),
+            ))
+        }
+    }
+
+    // whether resubmitting the same answer would get the same reply
+    pub fn is_final(&self) -> bool {
+        matches!(self, Verdict::Correct | Verdict::Incorrect { .. })
+    }
+
+    pub fn wait(&self) -> Option<Duration> {
+        match self {
+            Verdict::TooSoon { seconds } => Some(Duration::from_secs(*seconds)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect { hint: Some(hint) } => write!(f, "incorrect, {}", hint),
+            Verdict::Incorrect { hint: None } => write!(f, "incorrect"),
+            Verdict::TooSoon { seconds } => write!(f, "too soon, wait {}s", seconds),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+// the text of the page's <article>, or of the whole page, without tags
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+// "... You have 1m 2s left to wait." -> 62
+fn wait_seconds(text: &str) -> Option<u64> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|part| match part.split_at(part.len() - 1) {
+            (minutes, "m") => minutes.parse::<u64>().ok().map(|m| m * 60),
+            (seconds, "s") => seconds.parse().ok(),
+            _ => None,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_from_response() {
+        let correct = page("That's the right answer!  You are <em>one gold star</em> closer.");
+        assert_eq!(Verdict::from_response(&correct).unwrap(), Verdict::Correct);
+        let high = page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.");
+        assert_eq!(
+            Verdict::from_response(&high).unwrap(),
+            Verdict::Incorrect {
+                hint: Some("too high".to_string())
+            }
+        );
+        let wrong = page("That's not the right answer.  If you're stuck, try the subreddit.");
+        assert_eq!(
+            Verdict::from_response(&wrong).unwrap(),
+            Verdict::Incorrect { hint: None }
+        );
+        let soon = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 2s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a>");
+        assert_eq!(
+            Verdict::from_response(&soon).unwrap(),
+            Verdict::TooSoon { seconds: 62 }
+        );
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(
+            Verdict::from_response(&solved).unwrap(),
+            Verdict::AlreadySolved
+        );
+        assert!(matches!(
+            Verdict::from_response("<html>Maintenance</html>"),
+            Err(ClientError::UnexpectedResponse(text)) if text == "Maintenance"
+        ));
+    }
+
+    #[test]
+    fn test_wait_seconds() {
+        assert_eq!(wait_seconds("You have 42s left to wait."), Some(42));
+        assert_eq!(wait_seconds("You have 5m 0s left to wait."), Some(300));
+        assert_eq!(wait_seconds("You have a while left to wait."), None);
+        assert_eq!(wait_seconds("no wait"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Verdict::Correct.to_string(), "correct");
+        let low = Verdict::Incorrect {
+            hint: Some("too low".to_string()),
+        };
+        assert_eq!(low.to_string(), "incorrect, too low");
+        assert_eq!(
+            Verdict::TooSoon { seconds: 9 }.to_string(),
+            "too soon, wait 9s"
+        );
+        assert!(!Verdict::TooSoon { seconds: 9 }.is_final());
+        assert!(low.is_final());
+    }
+}
@@ -0,0 +1,242 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// running totals of the allocations made on one thread; live can go negative when memory
+// allocated on another thread is freed here
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        }
+    }
+
+    fn allocated(&mut self, size: usize) {
+        self.count += 1;
+        self.bytes += size as u64;
+        self.grow(size as i64);
+    }
+
+    fn grow(&mut self, size: i64) {
+        self.live += size;
+        self.peak = self.peak.max(self.live);
+    }
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::new()) };
+}
+
+fn count(update: impl FnOnce(&mut Counters)) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // the counters are gone while the thread shuts down
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        update(&mut current);
+        counters.set(current);
+    });
+}
+
+// the system allocator, counting what each thread allocates once enable is called; install it
+// with #[global_allocator] to profile solutions
+pub struct CountingAllocator;
+
+// SAFETY: every call is passed on unchanged to the system allocator, the counters only look at
+// the sizes and never allocate themselves
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(|counters| counters.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(|counters| counters.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(|counters| counters.grow(-(layout.size() as i64)));
+    }
+
+    // a reallocation counts as one allocation of the new size that frees the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(|counters| {
+                counters.grow(-(layout.size() as i64));
+                counters.allocated(new_size);
+            });
+        }
+        new_ptr
+    }
+}
+
+// start counting allocations; false, and nothing counted, unless CountingAllocator is the
+// global allocator
+pub fn enable() -> bool {
+    ENABLED.store(true, Ordering::Relaxed);
+    let before = COUNTERS.with(Cell::get).count;
+    drop(std::hint::black_box(Box::new(0u64)));
+    let counting = COUNTERS.with(Cell::get).count > before;
+    if !counting {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+    counting
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// what one phase of a solution allocated; peak_bytes is the most memory it held at once, over
+// what was live when it started
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    pub peak_bytes: u64,
+}
+
+// 1536 -> "1.5 KiB"
+pub fn format_bytes(bytes: u64) -> String {
+    let mut size = bytes as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if size < 1024.0 {
+            return match unit {
+                "B" => format!("{} B", bytes),
+                _ => format!("{:.1} {}", size, unit),
+            };
+        }
+        size /= 1024.0;
+    }
+    format!("{:.1} GiB", size)
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+// run f and count the allocations it makes on this thread, if counting is enabled
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+    let start = COUNTERS.with(|counters| {
+        let start = counters.get();
+        // the peak within f, restored below so an enclosing measure still sees it
+        counters.set(Counters {
+            peak: start.live,
+            ..start
+        });
+        start
+    });
+    let value = f();
+    let end = COUNTERS.with(|counters| {
+        let end = counters.get();
+        counters.set(Counters {
+            peak: end.peak.max(start.peak),
+            ..end
+        });
+        end
+    });
+    let allocations = Allocations {
+        count: end.count - start.count,
+        bytes: end.bytes - start.bytes,
+        peak_bytes: (end.peak - start.live).max(0) as u64,
+    };
+    (value, Some(allocations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        assert!(enable());
+        let (v, allocations) = measure(|| vec![0u8; 1000]);
+        let allocations = allocations.unwrap();
+        assert_eq!(v.len(), 1000);
+        assert_eq!(allocations.count, 1);
+        assert_eq!(allocations.bytes, 1000);
+        assert_eq!(allocations.peak_bytes, 1000);
+
+        // memory freed within the phase still counts towards its peak
+        let (_, allocations) = measure(|| {
+            drop(std::hint::black_box(vec![0u8; 4096]));
+            std::hint::black_box(vec![0u8; 16])
+        });
+        let allocations = allocations.unwrap();
+        assert_eq!(allocations.count, 2);
+        assert_eq!(allocations.bytes, 4112);
+        assert_eq!(allocations.peak_bytes, 4096);
+    }
+
+    #[test]
+    fn test_measure_nested() {
+        assert!(enable());
+        let (_, outer) = measure(|| {
+            let (inner, allocations) = measure(|| std::hint::black_box(vec![0u8; 64]));
+            assert_eq!(allocations.unwrap().peak_bytes, 64);
+            drop(inner);
+            std::hint::black_box(vec![0u8; 8])
+        });
+        let outer = outer.unwrap();
+        assert_eq!(outer.count, 2);
+        assert_eq!(outer.peak_bytes, 64);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        assert_eq!(format_bytes(5 << 30), "5.0 GiB");
+        let allocations = Allocations {
+            count: 3,
+            bytes: 2048,
+            peak_bytes: 100,
+        };
+        assert_eq!(
+            allocations.to_string(),
+            "3 allocations, 2.0 KiB allocated, 100 B peak"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+use std::io;
+
+use thiserror::Error;
+
+use crate::solution::Part;
+
+#[derive(Debug, Error)]
+pub enum AocError {
+    #[error("day {day} part {part} is not implemented")]
+    NotImplemented { day: u8, part: Part },
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    #[error("invalid input: {0}")]
+    Input(String),
+    #[error("{0}")]
+    Solve(String),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+// the position of offending text in the puzzle input, line and column count from 1
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}, column {column}: {reason}: `{text}`")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, reason: impl Display) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    // locate token, a slice borrowed from line, within the line numbered line_number
+    pub fn at(line_number: usize, line: &str, token: &str, reason: impl Display) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= line.len())
+            .or_else(|| line.find(token))
+            .unwrap_or(0);
+        let column = line[..offset].chars().count() + 1;
+        Self::new(line_number, column, token, reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_at() {
+        let line = "12 x4 7";
+        let token = line.split_ascii_whitespace().nth(1).unwrap();
+        let e = ParseError::at(3, line, token, "invalid digit");
+        assert_eq!(e, ParseError::new(3, 4, "x4", "invalid digit"));
+    }
+
+    #[test]
+    fn test_parse_error_at_counts_chars() {
+        let line = "é x";
+        let e = ParseError::at(1, line, &line[3..], "bad");
+        assert_eq!(e.column, 3);
+    }
+
+    #[test]
+    fn test_parse_error_at_copied_token() {
+        let e = ParseError::at(1, "ab cd", "cd", "bad");
+        assert_eq!(e.column, 4);
+    }
+
+    #[test]
+    fn test_display() {
+        let e = AocError::from(ParseError::new(2, 5, "x", "expected a number"));
+        assert_eq!(
+            e.to_string(),
+            "invalid input at line 2, column 5: expected a number: `x`"
+        );
+    }
+}
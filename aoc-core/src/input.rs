@@ -0,0 +1,286 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::report::warn;
+use crate::solution::Solution;
+
+// byte order mark some editors write at the start of a UTF-8 file
+const BOM: char = '\u{feff}';
+
+// how much of the input's shape normalization may touch
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    // lines of text, trailing whitespace is insignificant
+    #[default]
+    Lines,
+    // a rectangle of characters, lines of another width are reported
+    Grid,
+    // trailing whitespace is part of the puzzle and is kept
+    Verbatim,
+}
+
+// one change made while normalizing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fix {
+    ByteOrderMark,
+    // number of \r\n or lone \r line endings replaced by \n
+    LineEndings(usize),
+    // numbers of the lines trailing whitespace was removed from
+    TrailingWhitespace(Vec<usize>),
+    FinalNewline,
+}
+
+// lines listed in messages before the rest are counted
+const LISTED_LINES: usize = 5;
+
+fn line_list(lines: &[usize]) -> String {
+    let listed: Vec<String> = lines
+        .iter()
+        .take(LISTED_LINES)
+        .map(usize::to_string)
+        .collect();
+    match lines.len().checked_sub(LISTED_LINES) {
+        Some(more) if more > 0 => format!("{} and {} more", listed.join(", "), more),
+        _ => listed.join(", "),
+    }
+}
+
+impl Display for Fix {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Fix::ByteOrderMark => write!(f, "removed a byte order mark"),
+            Fix::LineEndings(count) => write!(f, "converted {} line endings to \\n", count),
+            Fix::TrailingWhitespace(lines) => write!(
+                f,
+                "removed trailing whitespace from line {}",
+                line_list(lines)
+            ),
+            Fix::FinalNewline => write!(f, "added a final newline"),
+        }
+    }
+}
+
+// a line of a grid input whose width differs from the first line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaggedLine {
+    pub line: usize,
+    pub width: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+    pub text: String,
+    pub fixes: Vec<Fix>,
+}
+
+// \n line endings, no BOM, no trailing whitespace unless verbatim, and a final newline
+pub fn normalize(raw: &str, layout: Layout) -> Normalized {
+    let mut fixes = Vec::new();
+    let mut rest = raw;
+    if let Some(stripped) = rest.strip_prefix(BOM) {
+        fixes.push(Fix::ByteOrderMark);
+        rest = stripped;
+    }
+
+    // \r\n, or a lone \r as in files from classic Mac OS, ends a line
+    let mut unified = String::with_capacity(rest.len());
+    let mut line_endings = 0;
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' {
+            chars.next_if_eq(&'\n');
+            unified.push('\n');
+            line_endings += 1;
+        } else {
+            unified.push(c);
+        }
+    }
+
+    let mut text = String::with_capacity(unified.len() + 1);
+    let mut trimmed_lines = Vec::new();
+    for (index, line) in unified.split('\n').enumerate() {
+        if index > 0 {
+            text.push('\n');
+        }
+        let kept = match layout {
+            Layout::Verbatim => line,
+            Layout::Lines | Layout::Grid => line.trim_end(),
+        };
+        if kept.len() != line.len() {
+            trimmed_lines.push(index + 1);
+        }
+        text.push_str(kept);
+    }
+
+    if line_endings > 0 {
+        fixes.push(Fix::LineEndings(line_endings));
+    }
+    if !trimmed_lines.is_empty() {
+        fixes.push(Fix::TrailingWhitespace(trimmed_lines));
+    }
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+        fixes.push(Fix::FinalNewline);
+    }
+    Normalized { text, fixes }
+}
+
+// lines whose width in characters differs from the first line, ignoring trailing blank lines
+pub fn ragged_lines(text: &str) -> Vec<RaggedLine> {
+    let lines: Vec<&str> = text.trim_end_matches('\n').split('\n').collect();
+    let width = lines[0].chars().count();
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| RaggedLine {
+            line: index + 1,
+            width: line.chars().count(),
+        })
+        .filter(|ragged| ragged.width != width)
+        .collect()
+}
+
+// normalize the input of a day, recording each fix and any ragged line as a warning
+pub fn load<S: Solution>(raw: &str) -> String {
+    let normalized = normalize(raw, S::LAYOUT);
+    for fix in normalized.fixes.iter() {
+        // most editors drop the final newline, not worth a warning
+        if *fix != Fix::FinalNewline {
+            warn(format!("input: {}", fix));
+        }
+    }
+    if S::LAYOUT == Layout::Grid {
+        let ragged = ragged_lines(&normalized.text);
+        if !ragged.is_empty() {
+            let lines: Vec<usize> = ragged.iter().map(|ragged| ragged.line).collect();
+            let width = normalized.text.lines().next().unwrap_or("").chars().count();
+            warn(format!(
+                "input: line {} not {} characters wide like line 1",
+                line_list(&lines),
+                width
+            ));
+        }
+    }
+    normalized.text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_normalize_clean_input() {
+        let normalized = normalize("ab\ncd\n", Layout::Lines);
+        assert_eq!(normalized.text, "ab\ncd\n");
+        assert!(normalized.fixes.is_empty());
+        assert_eq!(normalize("", Layout::Lines).text, "");
+    }
+
+    #[test]
+    fn test_normalize_crlf_and_bom() {
+        let normalized = normalize("\u{feff}ab\r\ncd\r\n", Layout::Lines);
+        assert_eq!(normalized.text, "ab\ncd\n");
+        assert_eq!(
+            normalized.fixes,
+            vec![Fix::ByteOrderMark, Fix::LineEndings(2)]
+        );
+    }
+
+    #[test]
+    fn test_normalize_lone_cr() {
+        let normalized = normalize("ab\rcd\r\ref", Layout::Lines);
+        assert_eq!(normalized.text, "ab\ncd\n\nef\n");
+        assert_eq!(
+            normalized.fixes,
+            vec![Fix::LineEndings(3), Fix::FinalNewline]
+        );
+    }
+
+    #[test]
+    fn test_normalize_trailing_whitespace() {
+        let normalized = normalize("47|53 \n \n75,47\t\n", Layout::Lines);
+        assert_eq!(normalized.text, "47|53\n\n75,47\n");
+        assert_eq!(
+            normalized.fixes,
+            vec![Fix::TrailingWhitespace(vec![1, 2, 3])]
+        );
+        let verbatim = normalize("    [D]\n[N] [C]    \n", Layout::Verbatim);
+        assert_eq!(verbatim.text, "    [D]\n[N] [C]    \n");
+        assert!(verbatim.fixes.is_empty());
+    }
+
+    #[test]
+    fn test_fix_display() {
+        assert_eq!(
+            Fix::LineEndings(3).to_string(),
+            "converted 3 line endings to \\n"
+        );
+        assert_eq!(
+            Fix::TrailingWhitespace((1..=7).collect()).to_string(),
+            "removed trailing whitespace from line 1, 2, 3, 4, 5 and 2 more"
+        );
+    }
+
+    #[test]
+    fn test_ragged_lines() {
+        assert!(ragged_lines("abc\ndef\n\n").is_empty());
+        assert_eq!(
+            ragged_lines("abc\nde\nfgh\nijkl\n"),
+            vec![
+                RaggedLine { line: 2, width: 2 },
+                RaggedLine { line: 4, width: 4 }
+            ]
+        );
+    }
+
+    struct Map;
+
+    impl Solution for Map {
+        const DAY: u8 = 0;
+        const LAYOUT: Layout = Layout::Grid;
+
+        type Input = String;
+        type Answer = String;
+
+        fn parse(input: &str) -> Result<Self::Input, crate::AocError> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer, crate::AocError> {
+            Ok(input.clone())
+        }
+    }
+
+    #[test]
+    fn test_load_warns() {
+        crate::report::take_warnings();
+        assert_eq!(load::<Map>("..#\r\n.#\r\n#.."), "..#\n.#\n#..\n");
+        assert_eq!(
+            crate::report::take_warnings(),
+            vec![
+                "input: converted 2 line endings to \\n",
+                "input: line 2 not 3 characters wide like line 1"
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_normalize_is_idempotent(raw in "\u{feff}?[ab \t\r\n]{0,60}") {
+            let once = normalize(&raw, Layout::Lines);
+            prop_assert!(!once.text.contains('\r'));
+            prop_assert!(!once.text.starts_with(BOM));
+            prop_assert!(once.text.is_empty() || once.text.ends_with('\n'));
+            let twice = normalize(&once.text, Layout::Lines);
+            prop_assert_eq!(&twice.text, &once.text);
+            prop_assert!(twice.fixes.is_empty());
+        }
+
+        #[test]
+        fn prop_normalize_keeps_clean_lines(lines in prop::collection::vec("[a-z#.]{0,10}", 0..10)) {
+            let text: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+            let crlf = text.replace('\n', "\r\n");
+            prop_assert_eq!(normalize(&crlf, Layout::Grid).text, text);
+        }
+    }
+}
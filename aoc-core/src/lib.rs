@@ -0,0 +1,19 @@
+// the #[aoc] expansion names this crate as ::aoc_core, also within it
+extern crate self as aoc_core;
+
+pub mod allocations;
+pub mod error;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod solution;
+
+pub use allocations::Allocations;
+pub use aoc_macros::{aoc, sample_test};
+pub use error::{AocError, ParseError};
+pub use input::Layout;
+pub use inventory;
+pub use registry::Solver;
+pub use report::{warn, OutputFormat, Report, ReportStatus};
+pub use solution::{run_main, solve, Part, Solution};
@@ -0,0 +1,278 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+// parse token, a slice borrowed from line, reporting its position on failure
+pub fn number<T>(line_number: usize, line: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse::<T>()
+        .map_err(|e| ParseError::at(line_number, line, token, e))
+}
+
+// the whitespace separated numbers on one line, without collecting them
+pub fn numbers_iter<'a, T>(
+    line_number: usize,
+    line: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+where
+    T: FromStr + 'a,
+    T::Err: Display,
+{
+    line.split_ascii_whitespace()
+        .map(move |token| number(line_number, line, token))
+}
+
+// the whitespace separated numbers on one line
+pub fn numbers<T>(line_number: usize, line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    numbers_iter(line_number, line).collect()
+}
+
+// the numbers on one line split by separator, every field must be a number
+pub fn separated<T>(line_number: usize, line: &str, separator: char) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    line.split(separator)
+        .map(|token| number(line_number, line, token))
+        .collect()
+}
+
+// the whitespace separated numbers on every line of the input
+pub fn number_rows<T>(input: &str) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| numbers(i + 1, line))
+        .collect()
+}
+
+// a run of non-blank lines, numbered from first_line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    // each line with its line number in the input
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| (self.first_line + i, *line))
+    }
+}
+
+// the sections of the input separated by one or more blank lines
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut in_section = false;
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            in_section = false;
+            continue;
+        }
+        match sections.last_mut() {
+            Some(section) if in_section => section.lines.push(line),
+            _ => sections.push(Section {
+                first_line: i + 1,
+                lines: vec![line],
+            }),
+        }
+        in_section = true;
+    }
+    sections
+}
+
+// cells of a rectangular character grid in row-major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharGrid<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+}
+
+// every row must have the width of the first, cell converts each character
+pub fn char_grid<T, E, F>(input: &str, mut cell: F) -> Result<CharGrid<T>, ParseError>
+where
+    E: Display,
+    F: FnMut(char) -> Result<T, E>,
+{
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    let mut height = 0;
+    let mut cells = Vec::with_capacity(input.len());
+    for (y, line) in input.lines().enumerate() {
+        for (offset, c) in line.char_indices().take(width) {
+            let token = &line[offset..offset + c.len_utf8()];
+            cells.push(cell(c).map_err(|e| ParseError::at(y + 1, line, token, e))?);
+        }
+        let row_width = line.chars().count();
+        if row_width != width {
+            let reason = format!("expected {} columns, found {}", width, row_width);
+            return Err(ParseError::new(
+                y + 1,
+                row_width.min(width) + 1,
+                line,
+                reason,
+            ));
+        }
+        height += 1;
+    }
+    Ok(CharGrid {
+        width,
+        height,
+        cells,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            numbers::<i64>(1, " 1   2 -3  4 5\t "),
+            Ok(vec![1, 2, -3, 4, 5])
+        );
+        assert_eq!(numbers::<i64>(1, ""), Ok(vec![]));
+        assert_eq!(
+            numbers::<u32>(4, "1 2 -3"),
+            Err(ParseError::new(4, 5, "-3", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_numbers_iter() {
+        let mut numbers = numbers_iter::<u8>(1, "1 x 3");
+        assert_eq!(numbers.next(), Some(Ok(1)));
+        assert!(numbers.next().unwrap().is_err());
+        assert_eq!(numbers.next(), Some(Ok(3)));
+        assert_eq!(numbers.next(), None);
+    }
+
+    #[test]
+    fn test_separated() {
+        assert_eq!(separated::<usize>(1, "75,47,61", ','), Ok(vec![75, 47, 61]));
+        assert_eq!(
+            separated::<usize>(2, "75,,61", ','),
+            Err(ParseError::new(
+                2,
+                4,
+                "",
+                "cannot parse integer from empty string"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_number_rows() {
+        assert_eq!(
+            number_rows::<i32>("1 2\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            number_rows::<i32>("1 2\n3 x\n"),
+            Err(ParseError::new(2, 3, "x", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let sections = sections("a\nb\n\n\nc\n \nd\n");
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].lines, vec!["a", "b"]);
+        assert_eq!(
+            sections[1].numbered_lines().collect::<Vec<_>>(),
+            vec![(5, "c")]
+        );
+        assert_eq!(sections[2].first_line, 7);
+    }
+
+    #[test]
+    fn test_sections_empty() {
+        assert!(sections("").is_empty());
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid("ab\ncd\n", Ok::<char, Infallible>).unwrap();
+        assert_eq!(grid.width, 2);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid.cells, vec!['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn test_char_grid_empty() {
+        let grid = char_grid("", Ok::<char, Infallible>).unwrap();
+        assert_eq!((grid.width, grid.height), (0, 0));
+    }
+
+    #[test]
+    fn test_char_grid_ragged() {
+        assert_eq!(
+            char_grid("abc\nabcd\n", Ok::<char, Infallible>),
+            Err(ParseError::new(2, 4, "abcd", "expected 3 columns, found 4"))
+        );
+        assert_eq!(
+            char_grid("abc\na\n", Ok::<char, Infallible>),
+            Err(ParseError::new(2, 2, "a", "expected 3 columns, found 1"))
+        );
+    }
+
+    #[test]
+    fn test_char_grid_invalid_cell() {
+        let digit = |c: char| c.to_digit(10).ok_or("expected a digit");
+        assert_eq!(
+            char_grid("12\n34\n", digit).unwrap().cells,
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            char_grid("12\n3é\n", digit),
+            Err(ParseError::new(2, 2, "é", "expected a digit"))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_number_rows_round_trip(
+            rows in prop::collection::vec(prop::collection::vec(any::<i64>(), 1..8), 0..20)
+        ) {
+            let lines: Vec<String> = rows
+                .iter()
+                .map(|row| row.iter().map(i64::to_string).collect::<Vec<_>>().join("  "))
+                .collect();
+            prop_assert_eq!(number_rows::<i64>(&lines.join("\n")), Ok(rows));
+        }
+
+        #[test]
+        fn prop_sections_keep_every_line(input in "[a-c \n]{0,80}") {
+            let kept: usize = sections(&input).iter().map(|s| s.lines.len()).sum();
+            let content = input.lines().filter(|line| !line.trim().is_empty()).count();
+            prop_assert_eq!(kept, content);
+        }
+
+        #[test]
+        fn prop_char_grid_never_panics(input in "\\PC{0,60}") {
+            let _ = char_grid(&input, |c: char| c.to_digit(10).ok_or("expected a digit"));
+        }
+    }
+}
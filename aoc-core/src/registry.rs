@@ -0,0 +1,193 @@
+use std::any::Any;
+
+use tracing::{debug_span, info_span};
+
+use crate::error::AocError;
+use crate::report::{report_phases, Report};
+use crate::solution::{Part, Solution};
+
+// a day's input after parsing, typed by the day's Solution
+pub type Parsed = Box<dyn Any>;
+
+// one part of one day, registered by #[aoc] on the day's impl of Solution or on a solver
+// function, which loads, parses and solves it
+pub struct Solver {
+    pub day: u8,
+    pub part: Part,
+    // path of the Solution method or function, as day_06::Day06::part1
+    pub name: &'static str,
+    pub load: fn(&str) -> String,
+    pub parse: fn(&str) -> Result<Parsed, AocError>,
+    pub solve: fn(&Parsed) -> Result<String, AocError>,
+}
+
+inventory::collect!(Solver);
+
+impl Solver {
+    // normalize, parse and solve the raw input
+    pub fn run(&self, input: &str) -> Result<String, AocError> {
+        let _day = info_span!("day", day = self.day, part = self.part.number()).entered();
+        let input = debug_span!("load").in_scope(|| (self.load)(input));
+        let parsed = info_span!("parse").in_scope(|| (self.parse)(&input))?;
+        info_span!("solve").in_scope(|| (self.solve)(&parsed))
+    }
+
+    // run, timing parsing and solving; input_name is the path the input was read from
+    pub fn report(&self, input: &str, input_name: &str) -> Report {
+        report_phases(
+            self.day, self.part, input, input_name, self.load, self.parse, self.solve,
+        )
+    }
+}
+
+// parse with the day's Solution, erasing the type for the registry
+pub fn parse<S: Solution>(input: &str) -> Result<Parsed, AocError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+// the input parsed by parse::<S>, for the solver registered with S
+pub fn input<S: Solution>(parsed: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref()
+        .expect("solvers are registered with the Solution that parses their input")
+}
+
+// solve part 1 with the Solution that parsed the input, rendering the answer
+pub fn part1<S: Solution>(parsed: &Parsed) -> Result<String, AocError>
+where
+    S::Input: 'static,
+{
+    S::part1(input::<S>(parsed)).map(|answer| answer.to_string())
+}
+
+pub fn part2<S: Solution>(parsed: &Parsed) -> Result<String, AocError>
+where
+    S::Input: 'static,
+{
+    S::part2(input::<S>(parsed)).map(|answer| answer.to_string())
+}
+
+// every registered solver, by day and part; panics if two solvers claim the same part, since
+// which one runs would depend on link order
+pub fn solvers() -> Vec<&'static Solver> {
+    let mut solvers: Vec<&Solver> = inventory::iter::<Solver>.into_iter().collect();
+    solvers.sort_by_key(|solver| (solver.day, solver.part));
+    if let Some((first, second)) = duplicate(&solvers) {
+        panic!(
+            "day {} part {} is registered twice, by {} and {}",
+            first.day, first.part, first.name, second.name
+        );
+    }
+    solvers
+}
+
+// the first two solvers for the same day and part, in a list sorted by day and part
+fn duplicate<'a>(solvers: &[&'a Solver]) -> Option<(&'a Solver, &'a Solver)> {
+    solvers
+        .windows(2)
+        .find(|pair| (pair[0].day, pair[0].part) == (pair[1].day, pair[1].part))
+        .map(|pair| (pair[0], pair[1]))
+}
+
+pub fn find(day: u8, part: Part) -> Option<&'static Solver> {
+    solvers()
+        .into_iter()
+        .find(|solver| solver.day == day && solver.part == part)
+}
+
+// the days with at least one registered solver, in order
+pub fn days() -> Vec<u8> {
+    let mut days: Vec<u8> = solvers().iter().map(|solver| solver.day).collect();
+    days.dedup();
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc;
+    use crate::report::ReportStatus;
+
+    struct Shout;
+
+    #[aoc]
+    impl Solution for Shout {
+        const DAY: u8 = 0;
+
+        type Input = Vec<String>;
+        type Answer = String;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(lines: &Self::Input) -> Result<Self::Answer, AocError> {
+            Ok(lines.join(" ").to_uppercase())
+        }
+    }
+
+    #[aoc(day = 0, part = 2, solution = Shout)]
+    fn count_lines(lines: &[String]) -> Result<usize, AocError> {
+        match lines.len() {
+            0 => Err(AocError::Solve("nothing to count".to_string())),
+            n => Ok(n),
+        }
+    }
+
+    #[test]
+    fn test_registered() {
+        let solver = find(0, Part::One).unwrap();
+        assert_eq!(solver.name, "aoc_core::registry::tests::Shout::part1");
+        assert_eq!(solver.run("ab\r\ncd").unwrap(), "AB CD");
+        assert_eq!(days(), vec![0]);
+        let parts: Vec<Part> = solvers().iter().map(|solver| solver.part).collect();
+        assert_eq!(parts, Part::ALL);
+        assert!(find(1, Part::One).is_none());
+    }
+
+    #[test]
+    fn test_fallible_solver() {
+        let solver = find(0, Part::Two).unwrap();
+        assert_eq!(solver.name, "aoc_core::registry::tests::count_lines");
+        assert_eq!(solver.run("a\nb\n").unwrap(), "2");
+        assert!(matches!(solver.run(""), Err(AocError::Solve(_))));
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let solver = |day, part, name| Solver {
+            day,
+            part,
+            name,
+            load: str::to_string,
+            parse: parse::<Shout>,
+            solve: part1::<Shout>,
+        };
+        let one = solver(1, Part::One, "day_01::Day01::part1");
+        let two = solver(1, Part::Two, "day_01::Day01::part2");
+        let again = solver(1, Part::One, "day_01_again::Day01::part1");
+        assert!(duplicate(&[&one, &two]).is_none());
+        let (first, second) = duplicate(&[&one, &again, &two]).unwrap();
+        assert_eq!(
+            (first.name, second.name),
+            ("day_01::Day01::part1", "day_01_again::Day01::part1")
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let report = find(0, Part::One).unwrap().report("ab\r\n", "-");
+        assert_eq!(report.status, ReportStatus::Solved);
+        assert_eq!(report.answer.as_deref(), Some("AB"));
+        assert_eq!(
+            report.warnings,
+            vec!["input: converted 1 line endings to \\n"]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use std::cell::RefCell;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug_span, info, info_span};
+
+use crate::allocations::{measure, Allocations};
+use crate::error::AocError;
+use crate::input::load;
+use crate::solution::{Part, Solution};
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+// record a diagnostic for the report of the part being solved on this thread, and emit it as a
+// warning event inside the day's span
+pub fn warn(message: impl Into<String>) {
+    let message = message.into();
+    tracing::warn!("{}", message);
+    WARNINGS.with(|warnings| warnings.borrow_mut().push(message));
+}
+
+// the diagnostics recorded on this thread since the last call
+pub fn take_warnings() -> Vec<String> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("unknown format `{}`, expected text or json", s)),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    Solved,
+    NotImplemented,
+    Failed,
+    // stopped by the runner's resource limits
+    TimedOut,
+    ExceededMemory,
+}
+
+impl ReportStatus {
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            ReportStatus::Failed | ReportStatus::TimedOut | ReportStatus::ExceededMemory
+        )
+    }
+}
+
+// the outcome of solving one part against one input, serialized for scripts and dashboards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub status: ReportStatus,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub warnings: Vec<String>,
+    // counted only when the runner profiles allocations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<Allocations>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solve_alloc: Option<Allocations>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports always serialize")
+    }
+}
+
+fn nanoseconds(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+// time loading, parsing and solving one day and part, collecting the warnings raised meanwhile
+pub(crate) fn report_phases<T>(
+    day: u8,
+    part: Part,
+    input: &str,
+    input_name: &str,
+    load: impl FnOnce(&str) -> String,
+    parse: impl FnOnce(&str) -> Result<T, AocError>,
+    solve: impl FnOnce(&T) -> Result<String, AocError>,
+) -> Report {
+    let _day = info_span!("day", day, part = part.number()).entered();
+    take_warnings();
+    let input = debug_span!("load").in_scope(|| load(input));
+    let start = Instant::now();
+    let (parsed, parse_alloc) = info_span!("parse").in_scope(|| measure(|| parse(&input)));
+    let elapsed = start.elapsed();
+    info!(?elapsed, ok = parsed.is_ok(), "parsed");
+    let parse_ns = Some(nanoseconds(elapsed));
+    let (result, solve_ns, solve_alloc) = match parsed {
+        Ok(parsed) => {
+            let start = Instant::now();
+            let (answer, allocations) = info_span!("solve").in_scope(|| measure(|| solve(&parsed)));
+            let elapsed = start.elapsed();
+            info!(?elapsed, ok = answer.is_ok(), "solved");
+            (answer, Some(nanoseconds(elapsed)), allocations)
+        }
+        Err(e) => (Err(e), None, None),
+    };
+    let (status, answer, error) = match result {
+        Ok(answer) => (ReportStatus::Solved, Some(answer), None),
+        Err(e @ AocError::NotImplemented { .. }) => {
+            (ReportStatus::NotImplemented, None, Some(e.to_string()))
+        }
+        Err(e) => (ReportStatus::Failed, None, Some(e.to_string())),
+    };
+    Report {
+        day,
+        part: part.number(),
+        input: input_name.to_string(),
+        status,
+        answer,
+        error,
+        parse_ns,
+        solve_ns,
+        warnings: take_warnings(),
+        parse_alloc,
+        solve_alloc,
+    }
+}
+
+// parse and solve one part, timing each phase; input_name is the path the input was read from
+pub fn report<S: Solution>(part: Part, input: &str, input_name: &str) -> Report {
+    report_phases(
+        S::DAY,
+        part,
+        input,
+        input_name,
+        load::<S>,
+        S::parse,
+        |parsed| match part {
+            Part::One => S::part1(parsed).map(|answer| answer.to_string()),
+            Part::Two => S::part2(parsed).map(|answer| answer.to_string()),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u8 = 0;
+
+        type Input = String;
+        type Answer = String;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            if input.is_empty() {
+                return Err(AocError::Input("empty input".to_string()));
+            }
+            Ok(input.trim().to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+            if input.contains(' ') {
+                warn("input contains spaces");
+            }
+            Ok(input.clone())
+        }
+    }
+
+    #[test]
+    fn test_report_solved() {
+        let report = report::<Echo>(Part::One, "a b\n", "-");
+        assert_eq!(report.status, ReportStatus::Solved);
+        assert_eq!(report.answer.as_deref(), Some("a b"));
+        assert_eq!(report.error, None);
+        assert!(report.parse_ns.is_some());
+        assert!(report.solve_ns.is_some());
+        assert_eq!(report.warnings, vec!["input contains spaces"]);
+        assert!(take_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_report_failed() {
+        let report = report::<Echo>(Part::One, "", "empty.dat");
+        assert_eq!(report.status, ReportStatus::Failed);
+        assert_eq!(report.error.as_deref(), Some("invalid input: empty input"));
+        assert_eq!(report.solve_ns, None);
+    }
+
+    #[test]
+    fn test_report_not_implemented() {
+        let report = report::<Echo>(Part::Two, "x", "x.dat");
+        assert_eq!(report.status, ReportStatus::NotImplemented);
+        assert_eq!(report.answer, None);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut report = report::<Echo>(Part::One, "ab", "input/day0-part1.dat");
+        report.parse_ns = Some(5);
+        report.solve_ns = Some(7);
+        report.parse_alloc = None;
+        report.solve_alloc = None;
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":0,"part":1,"input":"input/day0-part1.dat","status":"solved","answer":"ab","error":null,"parse_ns":5,"solve_ns":7,"warnings":[]}"#
+        );
+        let read: Report = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(read, report);
+
+        report.solve_alloc = Some(Allocations {
+            count: 2,
+            bytes: 48,
+            peak_bytes: 32,
+        });
+        let json = report.to_json();
+        assert!(
+            json.ends_with(r#""solve_alloc":{"count":2,"bytes":48,"peak_bytes":32}}"#),
+            "{}",
+            json
+        );
+        let read: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, report);
+    }
+
+    #[test]
+    fn test_status_is_failure() {
+        assert!(!ReportStatus::Solved.is_failure());
+        assert!(!ReportStatus::NotImplemented.is_failure());
+        assert!(ReportStatus::Failed.is_failure());
+        assert!(ReportStatus::TimedOut.is_failure());
+        assert!(ReportStatus::ExceededMemory.is_failure());
+        assert_eq!(
+            serde_json::to_string(&ReportStatus::ExceededMemory).unwrap(),
+            r#""exceeded_memory""#
+        );
+    }
+
+    #[test]
+    fn test_output_format() {
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
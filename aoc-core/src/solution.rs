@@ -0,0 +1,229 @@
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::error::AocError;
+use crate::input::{load, Layout};
+use crate::report::{report, OutputFormat, ReportStatus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(part),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+// A day's puzzle: the input is parsed once and shared by both parts
+pub trait Solution {
+    const DAY: u8;
+    // how the input loader may normalize the raw input
+    const LAYOUT: Layout = Layout::Lines;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError>;
+
+    fn part2(_input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Err(AocError::NotImplemented {
+            day: Self::DAY,
+            part: Part::Two,
+        })
+    }
+}
+
+// parse the input and solve one part, rendering the answer for display
+pub fn solve<S: Solution>(part: Part, input: &str) -> Result<String, AocError> {
+    let input = S::parse(&load::<S>(input))?;
+    let answer = match part {
+        Part::One => S::part1(&input)?,
+        Part::Two => S::part2(&input)?,
+    };
+    Ok(answer.to_string())
+}
+
+// command line of the day binaries: [--format text|json] [INPUT], stdin when INPUT is omitted or -
+fn parse_args(
+    args: impl Iterator<Item = String>,
+) -> Result<(OutputFormat, Option<String>), String> {
+    let mut format = OutputFormat::default();
+    let mut input = None;
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--format=") {
+            format = value.parse()?;
+        } else if arg == "--format" {
+            format = args.next().ok_or("--format requires a value")?.parse()?;
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option `{}`", arg));
+        } else if input.replace(arg).is_some() {
+            return Err("expected at most one input file".to_string());
+        }
+    }
+    Ok((format, input.filter(|input| input != "-")))
+}
+
+fn read_input(path: Option<&str>) -> io::Result<String> {
+    match path {
+        // real inputs may only be committed encrypted
+        Some(path) => aoc_crypt::read_input(Path::new(path)),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+// entry point shared by the day binaries: solve the puzzle read from a file or stdin
+pub fn run_main<S: Solution>(part: Part) -> ExitCode {
+    let (format, path) = match parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("usage: part{} [--format text|json] [INPUT]", part);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match read_input(path.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", AocError::from(e));
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = report::<S>(part, &input, path.as_deref().unwrap_or("-"));
+    match format {
+        OutputFormat::Json => println!("{}", report.to_json()),
+        OutputFormat::Text => {
+            for warning in report.warnings.iter() {
+                eprintln!("warning: {}", warning);
+            }
+            match (&report.answer, &report.error) {
+                (Some(answer), _) => println!("{}", answer),
+                (None, Some(e)) => eprintln!("error: {}", e),
+                (None, None) => {}
+            }
+        }
+    }
+    if report.status == ReportStatus::Solved {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        const DAY: u8 = 0;
+
+        type Input = Vec<String>;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            if input.is_empty() {
+                return Err(AocError::Input("empty input".to_string()));
+            }
+            Ok(input.split_whitespace().map(String::from).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_part_try_from() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3), Err(3));
+    }
+
+    #[test]
+    fn test_part_display() {
+        assert_eq!(Part::One.to_string(), "1");
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve::<WordCount>(Part::One, "a b c").unwrap(), "3");
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        assert!(matches!(
+            solve::<WordCount>(Part::One, ""),
+            Err(AocError::Input(_))
+        ));
+    }
+
+    #[test]
+    fn test_solve_not_implemented() {
+        assert!(matches!(
+            solve::<WordCount>(Part::Two, "a"),
+            Err(AocError::NotImplemented {
+                day: 0,
+                part: Part::Two
+            })
+        ));
+    }
+
+    fn args(args: &[&str]) -> Result<(OutputFormat, Option<String>), String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(args(&[]), Ok((OutputFormat::Text, None)));
+        assert_eq!(
+            args(&["--format", "json", "input.dat"]),
+            Ok((OutputFormat::Json, Some("input.dat".to_string())))
+        );
+        assert_eq!(
+            args(&["--format=json", "-"]),
+            Ok((OutputFormat::Json, None))
+        );
+        assert!(args(&["--format"]).is_err());
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+        assert!(args(&["a.dat", "b.dat"]).is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use std::env;
+use std::fmt::{self, Debug, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use thiserror::Error;
+
+// key file looked up in the input directory and each of its ancestors
+pub const KEY_FILE: &str = ".aoc-key";
+// hex key used when no key file is found, e.g. a CI secret
+pub const KEY_ENV: &str = "AOC_KEY";
+// appended to the file name of an encrypted input: day1-part1.dat.enc
+pub const EXTENSION: &str = "enc";
+
+// written ahead of the nonce so a stray file is not mistaken for ciphertext
+const MAGIC: &[u8] = b"aoc-crypt v1\n";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Error)]
+pub enum CryptError {
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error(
+        "input is encrypted but no key was found: create {} with `aoc-crypt keygen` or set {}",
+        KEY_FILE,
+        KEY_ENV
+    )]
+    MissingKey,
+    #[error("{0}: invalid key, expected 64 hex digits")]
+    InvalidKey(String),
+    #[error("{}: not an encrypted input", .0.display())]
+    NotEncrypted(PathBuf),
+    #[error("{}: decryption failed, wrong key or corrupted file", .0.display())]
+    Decrypt(PathBuf),
+    #[error("{}: decrypted input is not UTF-8", .0.display())]
+    Utf8(PathBuf),
+}
+
+impl From<CryptError> for io::Error {
+    fn from(e: CryptError) -> Self {
+        let kind = match &e {
+            CryptError::Io { source, .. } => source.kind(),
+            CryptError::MissingKey => io::ErrorKind::NotFound,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> CryptError + '_ {
+    move |source| CryptError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+// a 256 bit ChaCha20-Poly1305 key
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    // origin names where the text came from in the error
+    pub fn from_hex(text: &str, origin: &str) -> Result<Self, CryptError> {
+        let mut bytes = [0; 32];
+        hex::decode_to_slice(text.trim(), &mut bytes)
+            .map_err(|_| CryptError::InvalidKey(origin.to_string()))?;
+        Ok(Key(bytes))
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, CryptError> {
+        let text = fs::read_to_string(path).map_err(io_error(path))?;
+        Key::from_hex(&text, &path.display().to_string())
+    }
+
+    // written readable by the owner only
+    pub fn save(&self, path: &Path) -> Result<(), CryptError> {
+        fs::write(path, format!("{}\n", self.to_hex())).map_err(io_error(path))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(io_error(path))?;
+        }
+        Ok(())
+    }
+
+    // the nearest key file at or above dir, then the key in the environment
+    pub fn find(dir: &Path) -> Result<Self, CryptError> {
+        Key::find_with(dir, env::var(KEY_ENV).ok())
+    }
+
+    fn find_with(dir: &Path, env_key: Option<String>) -> Result<Self, CryptError> {
+        if let Some(path) = find_key_file(dir) {
+            return Key::load(&path);
+        }
+        // an empty variable counts as unset, as CI passes a secret that was never configured
+        match env_key.filter(|text| !text.is_empty()) {
+            Some(text) => Key::from_hex(&text, KEY_ENV),
+            None => Err(CryptError::MissingKey),
+        }
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let cipher = ChaCha20Poly1305::new(&self.0.into());
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext)
+            .expect("encryption only fails for inputs of many gigabytes");
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    // path names the encrypted file in errors
+    pub fn decrypt(&self, data: &[u8], path: &Path) -> Result<Vec<u8>, CryptError> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|rest| rest.len() >= NONCE_LEN)
+            .ok_or_else(|| CryptError::NotEncrypted(path.to_path_buf()))?;
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let cipher = ChaCha20Poly1305::new(&self.0.into());
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| CryptError::Decrypt(path.to_path_buf()))
+    }
+}
+
+// never print the key itself
+impl Debug for Key {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+pub fn find_key_file(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(KEY_FILE))
+        .find(|path| path.is_file())
+}
+
+// day1-part1.dat -> day1-part1.dat.enc
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+pub fn is_encrypted(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == EXTENSION)
+}
+
+// samples follow dayN-partM-sK.dat and stay in clear text
+pub fn is_sample(file_name: &str) -> bool {
+    file_name
+        .strip_suffix(".dat")
+        .and_then(|stem| stem.rsplit_once("-s"))
+        .is_some_and(|(_, n)| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+// the file holding the input at path: the path itself, its encrypted copy, or None
+pub fn locate_input(path: &Path) -> Option<PathBuf> {
+    if path.exists() {
+        return Some(path.to_path_buf());
+    }
+    let encrypted = encrypted_path(path);
+    encrypted.exists().then_some(encrypted)
+}
+
+pub fn input_exists(path: &Path) -> bool {
+    locate_input(path).is_some()
+}
+
+pub fn decrypt_file(key: &Key, path: &Path) -> Result<String, CryptError> {
+    let data = fs::read(path).map_err(io_error(path))?;
+    let plaintext = key.decrypt(&data, path)?;
+    String::from_utf8(plaintext).map_err(|_| CryptError::Utf8(path.to_path_buf()))
+}
+
+// read an input, decrypting it with the nearest key when only the .enc copy exists
+pub fn read_input(path: &Path) -> io::Result<String> {
+    let path = locate_input(path).unwrap_or_else(|| path.to_path_buf());
+    if !is_encrypted(&path) {
+        return fs::read_to_string(&path);
+    }
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let key = Key::find(dir)?;
+    Ok(decrypt_file(&key, &path)?)
+}
+
+// input files in dir, sorted by name
+fn inputs(dir: &Path, include: impl Fn(&str) -> bool) -> Result<Vec<PathBuf>, CryptError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error(dir))? {
+        let path = entry.map_err(io_error(dir))?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if include(&name) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+// replace every real input in dir by its encrypted copy, returning the files written
+pub fn encrypt_inputs(key: &Key, dir: &Path) -> Result<Vec<PathBuf>, CryptError> {
+    let mut written = Vec::new();
+    for path in inputs(dir, |name| name.ends_with(".dat") && !is_sample(name))? {
+        let plaintext = fs::read(&path).map_err(io_error(&path))?;
+        let encrypted = encrypted_path(&path);
+        fs::write(&encrypted, key.encrypt(&plaintext)).map_err(io_error(&encrypted))?;
+        fs::remove_file(&path).map_err(io_error(&path))?;
+        written.push(encrypted);
+    }
+    Ok(written)
+}
+
+// restore every encrypted input in dir to clear text, returning the files written
+pub fn decrypt_inputs(key: &Key, dir: &Path) -> Result<Vec<PathBuf>, CryptError> {
+    let mut written = Vec::new();
+    let suffix = format!(".dat.{}", EXTENSION);
+    for encrypted in inputs(dir, |name| name.ends_with(&suffix))? {
+        let plaintext = decrypt_file(key, &encrypted)?;
+        let path = encrypted.with_extension("");
+        fs::write(&path, plaintext).map_err(io_error(&path))?;
+        fs::remove_file(&encrypted).map_err(io_error(&encrypted))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> Key {
+        Key::from_hex(&"2a".repeat(32), "test").unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = key();
+        let data = key.encrypt(b"1   2\n");
+        assert!(data.starts_with(MAGIC));
+        assert_eq!(key.decrypt(&data, Path::new("x")).unwrap(), b"1   2\n");
+        assert_ne!(key.encrypt(b"1   2\n"), data);
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_key_and_plain_text() {
+        let data = key().encrypt(b"secret");
+        let other = Key::generate();
+        assert!(matches!(
+            other.decrypt(&data, Path::new("x")),
+            Err(CryptError::Decrypt(_))
+        ));
+        assert!(matches!(
+            key().decrypt(b"3   4\n", Path::new("x")),
+            Err(CryptError::NotEncrypted(_))
+        ));
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex(), "test").unwrap(), key);
+        let e = Key::from_hex("abc", "AOC_KEY").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "AOC_KEY: invalid key, expected 64 hex digits"
+        );
+        assert_eq!(format!("{:?}", key), "Key(..)");
+    }
+
+    #[test]
+    fn test_find_key() {
+        let root = tempfile::tempdir().unwrap();
+        let input_dir = root.path().join("input");
+        fs::create_dir(&input_dir).unwrap();
+        assert!(matches!(
+            Key::find_with(&input_dir, None),
+            Err(CryptError::MissingKey)
+        ));
+        assert!(matches!(
+            Key::find_with(&input_dir, Some(String::new())),
+            Err(CryptError::MissingKey)
+        ));
+        let env_key = Key::find_with(&input_dir, Some(key().to_hex())).unwrap();
+        assert_eq!(env_key, key());
+
+        let saved = Key::generate();
+        saved.save(&root.path().join(KEY_FILE)).unwrap();
+        assert_eq!(find_key_file(&input_dir), Some(root.path().join(KEY_FILE)));
+        // a key file wins over the environment
+        assert_eq!(
+            Key::find_with(&input_dir, Some(key().to_hex())).unwrap(),
+            saved
+        );
+    }
+
+    #[test]
+    fn test_is_sample() {
+        assert!(is_sample("day1-part1-s1.dat"));
+        assert!(is_sample("day12-part2-s10.dat"));
+        assert!(!is_sample("day1-part1.dat"));
+        assert!(!is_sample("day1-part1-s.dat"));
+        assert!(!is_sample("day1-part1-s1.txt"));
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = Key::generate();
+        key.save(&dir.path().join(KEY_FILE)).unwrap();
+        fs::write(dir.path().join("day1-part1.dat"), "3   4\n").unwrap();
+        fs::write(dir.path().join("day1-part1-s1.dat"), "1   1\n").unwrap();
+
+        let written = encrypt_inputs(&key, dir.path()).unwrap();
+        assert_eq!(written, vec![dir.path().join("day1-part1.dat.enc")]);
+        assert!(!dir.path().join("day1-part1.dat").exists());
+        assert!(dir.path().join("day1-part1-s1.dat").exists());
+
+        // read through the plain name, the key is found next to the input
+        let path = dir.path().join("day1-part1.dat");
+        assert!(input_exists(&path));
+        assert_eq!(read_input(&path).unwrap(), "3   4\n");
+        assert_eq!(
+            read_input(&dir.path().join("day1-part1-s1.dat")).unwrap(),
+            "1   1\n"
+        );
+
+        let written = decrypt_inputs(&key, dir.path()).unwrap();
+        assert_eq!(written, vec![path.clone()]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n");
+        assert!(!dir.path().join("day1-part1.dat.enc").exists());
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let e = read_input(&dir.path().join("day9-part1.dat")).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::NotFound);
+    }
+}
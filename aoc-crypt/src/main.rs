@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use aoc_crypt::{decrypt_inputs, encrypt_inputs, CryptError, Key};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc-crypt",
+    about = "Encrypt the personal puzzle inputs so they can be committed"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Write a new random key file
+    Keygen {
+        /// Key file to create
+        #[arg(long, default_value = aoc_crypt::KEY_FILE)]
+        key: PathBuf,
+    },
+    /// Replace every input except the samples by an encrypted .enc copy
+    Encrypt {
+        /// Directory holding the input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Key file, the nearest .aoc-key or AOC_KEY when omitted
+        #[arg(long)]
+        key: Option<PathBuf>,
+    },
+    /// Restore the encrypted inputs to clear text
+    Decrypt {
+        /// Directory holding the input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Key file, the nearest .aoc-key or AOC_KEY when omitted
+        #[arg(long)]
+        key: Option<PathBuf>,
+    },
+}
+
+fn load_key(key: Option<PathBuf>, input_dir: &Path) -> Result<Key, CryptError> {
+    match key {
+        Some(path) => Key::load(&path),
+        None => Key::find(input_dir),
+    }
+}
+
+fn run(command: Command) -> Result<Vec<PathBuf>, String> {
+    match command {
+        Command::Keygen { key } => {
+            if key.exists() {
+                return Err(format!("{} already exists", key.display()));
+            }
+            Key::generate().save(&key).map_err(|e| e.to_string())?;
+            Ok(vec![key])
+        }
+        Command::Encrypt { input_dir, key } => load_key(key, &input_dir)
+            .and_then(|key| encrypt_inputs(&key, &input_dir))
+            .map_err(|e| e.to_string()),
+        Command::Decrypt { input_dir, key } => load_key(key, &input_dir)
+            .and_then(|key| decrypt_inputs(&key, &input_dir))
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(written) => {
+            for path in written.iter() {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
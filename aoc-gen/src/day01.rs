@@ -0,0 +1,35 @@
+use rand::Rng;
+
+// lines of two five digit location ids; some right ids repeat left ones so similarity is not zero
+pub fn generate(rng: &mut impl Rng, lines: usize) -> String {
+    let left: Vec<u32> = (0..lines).map(|_| rng.gen_range(10000..100000)).collect();
+    let mut input = String::with_capacity(lines * 12);
+    for &l in left.iter() {
+        let r = if rng.gen_bool(0.3) {
+            left[rng.gen_range(0..lines)]
+        } else {
+            rng.gen_range(10000..100000)
+        };
+        input.push_str(&format!("{}   {}\n", l, r));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use day_01::Day01;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut StdRng::seed_from_u64(1), 500);
+        assert_eq!(input.lines().count(), 500);
+        assert!(solve::<Day01>(Part::One, &input).is_ok());
+        let similarity: u64 = solve::<Day01>(Part::Two, &input).unwrap().parse().unwrap();
+        assert!(similarity > 0);
+    }
+}
@@ -0,0 +1,57 @@
+use rand::Rng;
+
+// levels steadily increasing or decreasing by one to three
+fn safe_report(rng: &mut impl Rng, len: usize) -> Vec<i64> {
+    let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+    let mut level: i64 = if sign > 0 {
+        rng.gen_range(1..20)
+    } else {
+        rng.gen_range(80..99)
+    };
+    let mut levels = vec![level];
+    for _ in 1..len {
+        level += sign * rng.gen_range(1..=3);
+        levels.push(level);
+    }
+    levels
+}
+
+// lines of five to eight levels: a third safe, a third safe after removing one level, the rest noise
+pub fn generate(rng: &mut impl Rng, lines: usize) -> String {
+    let mut input = String::with_capacity(lines * 24);
+    for _ in 0..lines {
+        let len = rng.gen_range(5..=8);
+        let levels = match rng.gen_range(0..3) {
+            0 => safe_report(rng, len),
+            1 => {
+                let mut levels = safe_report(rng, len - 1);
+                levels.insert(rng.gen_range(0..len), rng.gen_range(1..99));
+                levels
+            }
+            _ => (0..len).map(|_| rng.gen_range(1..99)).collect(),
+        };
+        let line: Vec<String> = levels.iter().map(i64::to_string).collect();
+        input.push_str(&line.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use day_02::Day02;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut StdRng::seed_from_u64(2), 600);
+        assert_eq!(input.lines().count(), 600);
+        let safe: usize = solve::<Day02>(Part::One, &input).unwrap().parse().unwrap();
+        let dampened: usize = solve::<Day02>(Part::Two, &input).unwrap().parse().unwrap();
+        assert!(safe >= 150 && safe < dampened && dampened < 600);
+    }
+}
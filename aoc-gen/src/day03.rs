@@ -0,0 +1,62 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+// filler between instructions, free of digits so it never completes a mul into a huge number
+const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,;:'?+-/ _whatfromselectwhyhowwhere";
+
+// near misses of real instructions
+const DECOYS: &[&str] = &[
+    "mul[3,7]",
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "?mul(6,9!",
+    "don't",
+    "do(",
+    "mul(32,64]",
+    "undo",
+];
+
+// lines of about 3000 bytes of corrupted memory with mul, do and don't instructions
+pub fn generate(rng: &mut impl Rng, bytes: usize) -> String {
+    let mut input = String::with_capacity(bytes + 64);
+    let mut line = 0;
+    while input.len() < bytes {
+        let token = match rng.gen_range(0..20) {
+            0..=3 => format!("mul({},{})", rng.gen_range(1..1000), rng.gen_range(1..1000)),
+            4 => "do()".to_string(),
+            5 => "don't()".to_string(),
+            6 | 7 => DECOYS.choose(rng).unwrap().to_string(),
+            _ => (0..rng.gen_range(1..8))
+                .map(|_| *JUNK.choose(rng).unwrap() as char)
+                .collect(),
+        };
+        input.push_str(&token);
+        line += token.len();
+        if line >= 3000 {
+            input.push('\n');
+            line = 0;
+        }
+    }
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use day_03::Day03;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut StdRng::seed_from_u64(3), 10000);
+        assert!(input.len() >= 10000);
+        assert!(input.lines().count() >= 3);
+        let all: u64 = solve::<Day03>(Part::One, &input).unwrap().parse().unwrap();
+        let enabled: u64 = solve::<Day03>(Part::Two, &input).unwrap().parse().unwrap();
+        assert!(enabled > 0 && enabled < all);
+    }
+}
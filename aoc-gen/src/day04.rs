@@ -0,0 +1,34 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const LETTERS: &[u8] = b"XMAS";
+
+// a side x side word search of the letters X, M, A and S
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let mut input = String::with_capacity(side * (side + 1));
+    for _ in 0..side {
+        input.extend((0..side).map(|_| *LETTERS.choose(rng).unwrap() as char));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use day_04::Day04;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut StdRng::seed_from_u64(4), 60);
+        assert_eq!(input.lines().count(), 60);
+        assert!(input.lines().all(|line| line.len() == 60));
+        let words: u64 = solve::<Day04>(Part::One, &input).unwrap().parse().unwrap();
+        let crosses: u64 = solve::<Day04>(Part::Two, &input).unwrap().parse().unwrap();
+        assert!(words > 0 && crosses > 0);
+    }
+}
@@ -0,0 +1,67 @@
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+// pages in the rules, as in the real puzzle; odd so every page precedes exactly half the others
+const PAGES: usize = 49;
+const REACH: usize = PAGES / 2;
+
+// the pages sit on a circle and each must come before the next REACH pages clockwise, which
+// leaves the rules cyclic overall but ordered within any window of REACH + 1 pages; every update
+// is drawn from one window, half of them in order and half shuffled
+pub fn generate(rng: &mut impl Rng, updates: usize) -> String {
+    let pages: Vec<usize> = index::sample(rng, 90, PAGES)
+        .into_iter()
+        .map(|i| i + 10)
+        .collect();
+
+    let mut rules: Vec<(usize, usize)> = (0..PAGES)
+        .flat_map(|i| (1..=REACH).map(move |d| (i, (i + d) % PAGES)))
+        .map(|(before, after)| (pages[before], pages[after]))
+        .collect();
+    rules.shuffle(rng);
+
+    let mut input = String::with_capacity(rules.len() * 6 + updates * 40);
+    for (before, after) in rules.iter() {
+        input.push_str(&format!("{}|{}\n", before, after));
+    }
+    input.push('\n');
+    for _ in 0..updates {
+        let start = rng.gen_range(0..PAGES);
+        // an odd number of pages so there is a middle one
+        let len = rng.gen_range(2..=(REACH / 2).min(11)) * 2 + 1;
+        let mut offsets = index::sample(rng, REACH + 1, len).into_vec();
+        offsets.sort();
+        let mut update: Vec<usize> = offsets
+            .iter()
+            .map(|offset| pages[(start + offset) % PAGES])
+            .collect();
+        if rng.gen_bool(0.5) {
+            update.shuffle(rng);
+        }
+        let line: Vec<String> = update.iter().map(usize::to_string).collect();
+        input.push_str(&line.join(","));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use day_05::Day05;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut StdRng::seed_from_u64(5), 100);
+        let (rules, updates) = input.split_once("\n\n").unwrap();
+        assert_eq!(rules.lines().count(), PAGES * REACH);
+        assert_eq!(updates.lines().count(), 100);
+        let ordered: usize = solve::<Day05>(Part::One, &input).unwrap().parse().unwrap();
+        let repaired: usize = solve::<Day05>(Part::Two, &input).unwrap().parse().unwrap();
+        assert!(ordered > 0 && repaired > 0);
+    }
+}
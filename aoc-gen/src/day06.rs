@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+// share of cells holding an obstacle, about that of the real puzzle
+const OBSTACLE_CHANCE: f64 = 0.05;
+
+// random starts tried, the one with the longest walk off the map is kept
+const STARTS: usize = 32;
+
+// the steps the guard walking up from start takes to leave the map, or, when it is stuck in a
+// loop, the obstacle it turns at when it first repeats a position and heading
+fn walk(obstacles: &[bool], side: usize, start: (usize, usize)) -> Result<usize, usize> {
+    let deltas = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    let (mut x, mut y, mut heading) = (start.0, start.1, 0);
+    let mut seen = HashSet::new();
+    let mut steps = 0;
+    loop {
+        let (dx, dy) = deltas[heading];
+        let next = x
+            .checked_add_signed(dx)
+            .zip(y.checked_add_signed(dy))
+            .filter(|&(nx, ny)| nx < side && ny < side);
+        let Some((nx, ny)) = next else {
+            return Ok(steps);
+        };
+        if obstacles[ny * side + nx] {
+            if !seen.insert((x, y, heading)) {
+                return Err(ny * side + nx);
+            }
+            heading = (heading + 1) % 4;
+        } else {
+            x = nx;
+            y = ny;
+            steps += 1;
+        }
+    }
+}
+
+// a side x side map with a guard facing up on a long walk; when every start tried ends in a
+// loop, obstacles are removed until the guard walks off the map
+pub fn generate(rng: &mut impl Rng, side: usize) -> String {
+    let side = side.max(1);
+    let mut obstacles: Vec<bool> = (0..side * side)
+        .map(|_| rng.gen_bool(OBSTACLE_CHANCE))
+        .collect();
+    let starts: Vec<(usize, usize)> = (0..STARTS)
+        .map(|_| (rng.gen_range(0..side), rng.gen_range(0..side)))
+        .filter(|&(x, y)| !obstacles[y * side + x])
+        .collect();
+    let longest = starts
+        .iter()
+        .filter_map(|&start| Some((walk(&obstacles, side, start).ok()?, start)))
+        .max();
+    let start = match longest {
+        Some((_, start)) => start,
+        None => {
+            let start = starts.first().copied().unwrap_or((0, 0));
+            obstacles[start.1 * side + start.0] = false;
+            while let Err(obstacle) = walk(&obstacles, side, start) {
+                obstacles[obstacle] = false;
+            }
+            start
+        }
+    };
+
+    let mut input = String::with_capacity(side * (side + 1));
+    for y in 0..side {
+        for x in 0..side {
+            input.push(match ((x, y) == start, obstacles[y * side + x]) {
+                (true, _) => '^',
+                (false, true) => '#',
+                (false, false) => '.',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use day_06::Day06;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        // . # . .
+        // . . . #
+        // ^ . . .
+        // . . # .
+        // the guard turns at (1, 0), (3, 1) and (2, 3), then walks off the left edge
+        let mut obstacles = vec![false; 16];
+        for i in [1, 7, 14] {
+            obstacles[i] = true;
+        }
+        assert_eq!(walk(&obstacles, 4, (1, 2)), Ok(5));
+        // a fourth obstacle at (0, 2) closes the loop
+        obstacles[8] = true;
+        assert_eq!(walk(&obstacles, 4, (1, 2)), Err(1));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 40);
+            assert_eq!(input.matches('^').count(), 1);
+            let visited: usize = solve::<Day06>(Part::One, &input).unwrap().parse().unwrap();
+            assert!(visited >= 1);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::ops::RangeInclusive;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+
+// days with a generator
+pub const DAYS: RangeInclusive<u8> = 1..=6;
+
+// roughly the size of a real input: lines for days 1, 2 and 5, bytes for day 3, the side for 4 and 6
+pub fn default_size(day: u8) -> Option<usize> {
+    match day {
+        1 => Some(1000),
+        2 => Some(1000),
+        3 => Some(18000),
+        4 => Some(140),
+        5 => Some(200),
+        6 => Some(130),
+        _ => None,
+    }
+}
+
+// a valid input for the day, the same for the same size and seed; None for days without a generator
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let rng = &mut rng;
+    match day {
+        1 => Some(day01::generate(rng, size)),
+        2 => Some(day02::generate(rng, size)),
+        3 => Some(day03::generate(rng, size)),
+        4 => Some(day04::generate(rng, size)),
+        5 => Some(day05::generate(rng, size)),
+        6 => Some(day06::generate(rng, size)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_seeded() {
+        for day in DAYS {
+            let size = default_size(day).unwrap() / 10;
+            assert_eq!(generate(day, size, 7), generate(day, size, 7));
+            assert_ne!(generate(day, size, 7), generate(day, size, 8));
+        }
+    }
+
+    #[test]
+    fn test_generate_unknown_day() {
+        assert_eq!(generate(7, 10, 0), None);
+        assert_eq!(default_size(7), None);
+    }
+}
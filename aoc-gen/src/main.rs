@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use aoc_gen::{default_size, generate, DAYS};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc-gen",
+    about = "Generate valid puzzle inputs of any size for stress tests and benchmarks"
+)]
+struct Cli {
+    /// Day to generate, every day when omitted
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Lines for days 1, 2 and 5, bytes for day 3, width and height for days 4 and 6;
+    /// about the size of a real input when omitted
+    #[arg(short, long)]
+    size: Option<usize>,
+    /// Seed of the random generator, the same seed gives the same input
+    #[arg(long, default_value_t = 2024)]
+    seed: u64,
+    /// Write dayN-part1.dat files here, as read by `aoc run` and `aoc bench` with --input-dir;
+    /// print to stdout when omitted
+    #[arg(short, long)]
+    output_dir: Option<PathBuf>,
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let days: Vec<u8> = match cli.day {
+        Some(day) => vec![day],
+        None if cli.output_dir.is_some() => DAYS.collect(),
+        None => return Err("--day is required when printing to stdout".to_string()),
+    };
+    for day in days {
+        let size = cli
+            .size
+            .or_else(|| default_size(day))
+            .ok_or_else(|| format!("no generator for day {}", day))?;
+        let input =
+            generate(day, size, cli.seed).ok_or_else(|| format!("no generator for day {}", day))?;
+        match &cli.output_dir {
+            Some(dir) => {
+                let path = dir.join(format!("day{}-part1.dat", day));
+                fs::create_dir_all(dir)
+                    .and_then(|_| fs::write(&path, input))
+                    .map_err(|e| format!("{}: {}", path.display(), e))?;
+                println!("wrote {}", path.display());
+            }
+            None => print!("{}", input),
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+// a signed displacement between two points
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    // steps needed moving only orthogonally
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    // steps needed moving orthogonally or diagonally
+    pub fn chebyshev(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+// a position on a grid, x counts columns and y rows from the top left
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // None if the result would have a negative coordinate
+    pub fn checked_add(self, delta: Vec2) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+
+    // None unless the result is inside a width x height grid
+    pub fn checked_add_within(self, delta: Vec2, width: usize, height: usize) -> Option<Point> {
+        self.checked_add(delta)
+            .filter(|point| point.x < width && point.y < height)
+    }
+
+    // the displacement from other to this point
+    pub fn delta_from(self, other: Point) -> Vec2 {
+        Vec2::new(
+            self.x as isize - other.x as isize,
+            self.y as isize - other.y as isize,
+        )
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// the orthogonal headings, in clockwise order from up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction4> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction4::Up => Vec2::new(0, -1),
+            Direction4::Right => Vec2::new(1, 0),
+            Direction4::Down => Vec2::new(0, 1),
+            Direction4::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction4 {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction4 {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction4 {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    // the ^ > v < arrows puzzles use to draw a heading
+    pub fn from_arrow(arrow: char) -> Option<Direction4> {
+        match arrow {
+            '^' => Some(Direction4::Up),
+            '>' => Some(Direction4::Right),
+            'v' => Some(Direction4::Down),
+            '<' => Some(Direction4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::Up => '^',
+            Direction4::Right => '>',
+            Direction4::Down => 'v',
+            Direction4::Left => '<',
+        }
+    }
+}
+
+// the orthogonal and diagonal headings, in clockwise order from up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn delta(self) -> Vec2 {
+        match self {
+            Direction8::Up => Vec2::new(0, -1),
+            Direction8::UpRight => Vec2::new(1, -1),
+            Direction8::Right => Vec2::new(1, 0),
+            Direction8::DownRight => Vec2::new(1, 1),
+            Direction8::Down => Vec2::new(0, 1),
+            Direction8::DownLeft => Vec2::new(-1, 1),
+            Direction8::Left => Vec2::new(-1, 0),
+            Direction8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    // an eighth of a turn clockwise
+    pub fn turn_right(self) -> Direction8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    // an eighth of a turn counterclockwise
+    pub fn turn_left(self) -> Direction8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec2_arithmetic() {
+        let v = Vec2::new(2, -3);
+        assert_eq!(v + Vec2::new(1, 1), Vec2::new(3, -2));
+        assert_eq!(v - v, Vec2::ZERO);
+        assert_eq!(-v, Vec2::new(-2, 3));
+        assert_eq!(v * 2, Vec2::new(4, -6));
+    }
+
+    #[test]
+    fn test_vec2_lengths() {
+        assert_eq!(Vec2::new(2, -3).manhattan(), 5);
+        assert_eq!(Vec2::new(2, -3).chebyshev(), 3);
+    }
+
+    #[test]
+    fn test_point_checked_add() {
+        let p = Point::new(1, 1);
+        assert_eq!(p.checked_add(Vec2::new(-1, 2)), Some(Point::new(0, 3)));
+        assert_eq!(p.checked_add(Vec2::new(-2, 0)), None);
+        assert_eq!(
+            p.checked_add_within(Vec2::new(1, 0), 3, 3),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(p.checked_add_within(Vec2::new(2, 0), 3, 3), None);
+        assert_eq!(p.checked_add_within(Vec2::new(0, 2), 3, 3), None);
+    }
+
+    #[test]
+    fn test_point_distances() {
+        let a = Point::new(1, 5);
+        let b = Point::new(4, 1);
+        assert_eq!(a.delta_from(b), Vec2::new(-3, 4));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.manhattan_distance(a), 0);
+    }
+
+    #[test]
+    fn test_direction4_turns() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Down.reverse(), Direction4::Up);
+        for direction in Direction4::iter() {
+            assert_eq!(direction.delta() + direction.reverse().delta(), Vec2::ZERO);
+            assert_eq!(
+                direction.turn_right().turn_right().turn_right(),
+                direction.turn_left()
+            );
+        }
+    }
+
+    #[test]
+    fn test_direction4_arrows() {
+        for direction in Direction4::iter() {
+            assert_eq!(Direction4::from_arrow(direction.arrow()), Some(direction));
+        }
+        assert_eq!(Direction4::from_arrow('.'), None);
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::iter().count(), 8);
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+        assert!(Direction8::DownRight.is_diagonal());
+        assert!(!Direction8::Left.is_diagonal());
+        for direction in Direction8::iter() {
+            assert_eq!(direction.delta().chebyshev(), 1);
+            assert_eq!(direction.delta() + direction.reverse().delta(), Vec2::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_direction4_into_direction8() {
+        for direction in Direction4::iter() {
+            assert_eq!(Direction8::from(direction).delta(), direction.delta());
+        }
+    }
+}
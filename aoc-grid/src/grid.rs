@@ -0,0 +1,532 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use aoc_core::parse::{self, CharGrid};
+use aoc_core::ParseError;
+use thiserror::Error;
+
+use crate::geometry::{Direction4, Direction8, Point, Vec2};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GridError {
+    #[error("position ({x}, {y}) is outside the {width}x{height} grid")]
+    OutOfBounds {
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    },
+}
+
+// a dense rectangular grid stored in row-major order, x counts columns and y rows from the top left
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // None unless there is exactly one cell for every position
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() != width * height {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    // rows of equal width, cell converts each character
+    pub fn parse_with<E, F>(input: &str, cell: F) -> Result<Self, ParseError>
+    where
+        E: Display,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Ok(Self::from(parse::char_grid(input, cell)?))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        self.in_bounds(x, y).then(|| y * self.width + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // negative coordinates are simply outside the grid
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        let x = usize::try_from(x).ok()?;
+        let y = usize::try_from(y).ok()?;
+        self.get(x, y)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Result<(), GridError> {
+        let (width, height) = (self.width, self.height);
+        let cell = self.get_mut(x, y).ok_or(GridError::OutOfBounds {
+            x,
+            y,
+            width,
+            height,
+        })?;
+        *cell = value;
+        Ok(())
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.in_bounds(point.x, point.y)
+    }
+
+    // the point delta away, None if that leaves the grid
+    pub fn step(&self, point: Point, delta: Vec2) -> Option<Point> {
+        point.checked_add_within(delta, self.width, self.height)
+    }
+
+    // every point in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // the first point, in row-major order, whose cell matches predicate
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    // up, right, down and left of the point, where they are inside the grid
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction4::iter().filter_map(move |direction| self.step(point, direction.delta()))
+    }
+
+    // the 8 surrounding points clockwise from up, where they are inside the grid
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::iter().filter_map(move |direction| self.step(point, direction.delta()))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero width, which only an empty grid has
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    // points from start stepping by delta until leaving the grid
+    pub fn ray(&self, start: Point, delta: Vec2) -> impl Iterator<Item = Point> + '_ {
+        let start = self.contains(start).then_some(start);
+        std::iter::successors(start, move |&point| self.step(point, delta))
+    }
+
+    // the down-right diagonals, starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((1..self.width).map(|x| Point::new(x, 0)));
+        let delta = Direction8::DownRight.delta();
+        starts.map(move |start| self.ray(start, delta).map(move |point| &self[point]))
+    }
+
+    // the down-left diagonals, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..self.height).map(move |y| Point::new(last, y)));
+        let delta = Direction8::DownLeft.delta();
+        starts.map(move |start| self.ray(start, delta).map(move |point| &self[point]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // build a width x height grid taking each cell from this one
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> usize) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self.cells[source(i % width, i / width)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // swap rows and columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| x * width + y)
+    }
+
+    // mirror left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(width, self.height, |x, y| y * width + (width - 1 - x))
+    }
+
+    // mirror top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |x, y| (height - 1 - y) * width + x)
+    }
+
+    // a quarter turn clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width, self.height);
+        self.remap(height, width, |x, y| (height - 1 - x) * width + y)
+    }
+
+    // a quarter turn counterclockwise
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |x, y| x * width + (width - 1 - y))
+    }
+
+    // skew by 45 degrees so both diagonals become rows and columns, (x, y) moves to
+    // (x + y, x - y + height - 1) and the positions between cells are None
+    pub fn rotate_45(&self) -> Grid<Option<T>>
+    where
+        T: Clone,
+    {
+        let size = (self.width + self.height).saturating_sub(1);
+        let mut rotated = Grid::new(size, size, None);
+        for (Point { x, y }, cell) in self.iter() {
+            rotated[Point::new(x + y, x + self.height - 1 - y)] = Some(cell.clone());
+        }
+        rotated
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, Ok::<char, Infallible>)
+    }
+}
+
+impl<T> From<CharGrid<T>> for Grid<T> {
+    fn from(grid: CharGrid<T>) -> Self {
+        Self {
+            width: grid.width,
+            height: grid.height,
+            cells: grid.cells,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point.x, point.y)
+            .expect("point is outside the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point.x, point.y)
+            .expect("point is outside the grid")
+    }
+}
+
+// one line per row
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuv";
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n").unwrap()
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(10, 20, 0);
+        assert_eq!(grid.width(), 10);
+        assert_eq!(grid.height(), 20);
+        assert!(grid.iter().all(|(_, &cell)| cell == 0));
+    }
+
+    #[test]
+    fn test_set_get() {
+        let mut grid = Grid::new(10, 10, '.');
+        grid.set(2, 3, 'a').unwrap();
+        assert_eq!(grid.get(2, 3), Some(&'a'));
+        assert_eq!(grid.get(3, 2), Some(&'.'));
+        assert_eq!(grid[Point::new(2, 3)], 'a');
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut grid = Grid::new(10, 10, 0);
+        assert_eq!(grid.get(10, 0), None);
+        assert_eq!(grid.get(0, 10), None);
+        assert!(!grid.in_bounds(10, 10));
+        assert_eq!(
+            grid.set(11, 0, 42),
+            Err(GridError::OutOfBounds {
+                x: 11,
+                y: 0,
+                width: 10,
+                height: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_signed() {
+        let grid = sample();
+        assert_eq!(grid.get_signed(1, 1), Some(&'e'));
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(0, -1), None);
+        assert_eq!(grid.get_signed(3, 0), None);
+    }
+
+    #[test]
+    fn test_from_cells() {
+        let grid = Grid::from_cells(10, 10, LETTERS.chars().collect()).unwrap();
+        assert_eq!(grid.get(0, 0), Some(&'a'));
+        assert_eq!(grid.get(9, 9), Some(&'v'));
+        assert!(Grid::from_cells(3, 3, vec![0; 8]).is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert!(Grid::parse("abc\nde").is_err());
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("12\n34", |c| c.to_digit(10).ok_or("expected a digit"));
+        assert_eq!(grid.unwrap().get(1, 1), Some(&4));
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = sample();
+        let point = Point::new(1, 1);
+        assert!(grid.contains(point));
+        assert_eq!(grid.step(point, Vec2::new(1, -1)), Some(Point::new(2, 0)));
+        assert_eq!(grid.step(Point::new(0, 0), Vec2::new(-1, 0)), None);
+        assert_eq!(grid.step(Point::new(2, 1), Vec2::new(0, 1)), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = sample();
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(|&c| c == 'z'), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        let points = |points: &[(usize, usize)]| -> Vec<Point> {
+            points.iter().map(|&p| Point::from(p)).collect()
+        };
+        assert_eq!(
+            grid.neighbors4(Point::new(1, 1)).collect::<Vec<_>>(),
+            points(&[(1, 0), (2, 1), (1, 2), (0, 1)])
+        );
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            points(&[(1, 0), (0, 1)])
+        );
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors8(Point::new(2, 2)).collect::<Vec<_>>(),
+            points(&[(2, 1), (1, 2), (1, 1)])
+        );
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(collect(grid.column(1)), "be");
+        assert_eq!(collect(grid.column(3)), "");
+        let columns: Vec<String> = grid.columns().map(collect).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = sample();
+        let diagonals: Vec<String> = grid.diagonals().map(collect).collect();
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        let anti_diagonals: Vec<String> = grid.anti_diagonals().map(collect).collect();
+        assert_eq!(anti_diagonals, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = sample();
+        assert_eq!(
+            grid.ray(Point::new(0, 1), Direction8::UpRight.delta())
+                .collect::<Vec<_>>(),
+            vec![Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.ray(Point::new(3, 0), Vec2::new(1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_left().rotate_right(), grid);
+    }
+
+    #[test]
+    fn test_rotate_45() {
+        let grid = Grid::parse("M.S\n.A.\nM.S").unwrap();
+        let rotated = grid.rotate_45();
+        assert_eq!((rotated.width(), rotated.height()), (5, 5));
+        let shown = rotated.map(|cell| cell.unwrap_or(' '));
+        assert_eq!(shown.to_string(), "  M  \n . . \nM A S\n . . \n  S  \n");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(sample().to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = sample().map(|&c| c == 'e');
+        assert_eq!(grid.find(|&e| e), Some(Point::new(1, 1)));
+    }
+
+    fn char_grids() -> impl Strategy<Value = Grid<char>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::char::ranges(vec!['a'..='z', '#'..='#'].into()),
+                width * height,
+            )
+            .prop_map(move |cells| Grid::from_cells(width, height, cells).unwrap())
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_display_parse_round_trip(grid in char_grids()) {
+            prop_assert_eq!(Grid::parse(&grid.to_string()), Ok(grid));
+        }
+
+        #[test]
+        fn prop_transforms_invert(grid in char_grids()) {
+            prop_assert_eq!(&grid.transpose().transpose(), &grid);
+            prop_assert_eq!(&grid.flip_horizontal().flip_horizontal(), &grid);
+            prop_assert_eq!(&grid.flip_vertical().flip_vertical(), &grid);
+            prop_assert_eq!(&grid.rotate_right().rotate_left(), &grid);
+            let four = grid.rotate_right().rotate_right().rotate_right().rotate_right();
+            prop_assert_eq!(&four, &grid);
+        }
+
+        #[test]
+        fn prop_parse_never_panics(input in "[a#\n]{0,40}") {
+            if let Ok(grid) = Grid::parse(&input) {
+                prop_assert_eq!(grid.iter().count(), grid.width() * grid.height());
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod geometry;
+pub mod grid;
+
+pub use geometry::{Direction4, Direction8, Point, Vec2};
+pub use grid::{Grid, GridError};
@@ -0,0 +1,584 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{
+    Error, Expr, ExprLit, FnArg, Ident, ImplItem, Item, ItemFn, ItemImpl, Lit, LitInt, LitStr,
+    MetaNameValue, Path, ReturnType, Token, Type,
+};
+
+// sample_test!(day = 4, part = 2, file = "day4-part1-s1.dat", expect = 9) expands to a test that
+// solves input/day4-part1-s1.dat with crate::Day04 and compares the answer with expect; optional
+// solution = path::Type names another solver and ignore = "reason" marks the test ignored
+#[proc_macro]
+pub fn sample_test(input: TokenStream) -> TokenStream {
+    expand_sample_test(input.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+// #[aoc] on a day's `impl Solution for DayNN` registers its part1, and its part2 when the impl
+// defines one, with the runner, so the runner solves through the same impl as the day binaries.
+// #[aoc(day = 6, part = 1)] on a function taking the parsed input of crate::Day06 registers that
+// function as the solver of the part instead; optional solution = path::Type names another
+// Solution, whose DAY must match
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    expand_aoc(args.into(), item.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+// name = value arguments of sample_test! and of #[aoc] on a function
+#[derive(Default)]
+struct Args {
+    day: Option<u8>,
+    part: Option<u8>,
+    file: Option<LitStr>,
+    expect: Option<Expr>,
+    solution: Option<Path>,
+    ignore: Option<LitStr>,
+}
+
+struct Registration {
+    day: u8,
+    part: u8,
+    solution: Option<Path>,
+}
+
+struct SampleTest {
+    day: u8,
+    part: u8,
+    file: LitStr,
+    expect: Expr,
+    solution: Option<Path>,
+    ignore: Option<LitStr>,
+}
+
+fn lit_int(value: &Expr) -> syn::Result<&LitInt> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => Ok(lit),
+        _ => Err(Error::new_spanned(value, "expected an integer literal")),
+    }
+}
+
+fn lit_str(value: &Expr) -> syn::Result<&LitStr> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit),
+        _ => Err(Error::new_spanned(value, "expected a string literal")),
+    }
+}
+
+// parse the arguments, rejecting any not in allowed
+fn parse_args(input: TokenStream2, allowed: &[&str]) -> syn::Result<Args> {
+    let mut args = Args::default();
+    for arg in Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(input)? {
+        let Some(key) = arg.path.get_ident() else {
+            return Err(Error::new_spanned(&arg.path, "expected an argument name"));
+        };
+        let name = key.to_string();
+        if !allowed.contains(&name.as_str()) {
+            return Err(Error::new_spanned(
+                key,
+                format!("unknown argument, expected {}", allowed.join(", ")),
+            ));
+        }
+        match name.as_str() {
+            "day" => args.day = Some(lit_int(&arg.value)?.base10_parse::<u8>()?),
+            "part" => {
+                let lit = lit_int(&arg.value)?;
+                match lit.base10_parse::<u8>()? {
+                    n @ (1 | 2) => args.part = Some(n),
+                    _ => return Err(Error::new_spanned(lit, "part must be 1 or 2")),
+                }
+            }
+            "file" => args.file = Some(lit_str(&arg.value)?.clone()),
+            "expect" => args.expect = Some(arg.value),
+            "solution" => match arg.value {
+                Expr::Path(path) => args.solution = Some(path.path),
+                value => return Err(Error::new_spanned(value, "expected a type path")),
+            },
+            "ignore" => args.ignore = Some(lit_str(&arg.value)?.clone()),
+            _ => unreachable!("argument {} is allowed but not parsed", name),
+        }
+    }
+    Ok(args)
+}
+
+fn required<T>(value: Option<T>, name: &str) -> syn::Result<T> {
+    value.ok_or_else(|| Error::new(Span::call_site(), format!("missing argument `{}`", name)))
+}
+
+fn parse_sample_test(input: TokenStream2) -> syn::Result<SampleTest> {
+    let args = parse_args(
+        input,
+        &["day", "part", "file", "expect", "solution", "ignore"],
+    )?;
+    Ok(SampleTest {
+        day: required(args.day, "day")?,
+        part: required(args.part, "part")?,
+        file: required(args.file, "file")?,
+        expect: required(args.expect, "expect")?,
+        solution: args.solution,
+        ignore: args.ignore,
+    })
+}
+
+fn parse_registration(input: TokenStream2) -> syn::Result<Registration> {
+    let args = parse_args(input, &["day", "part", "solution"])?;
+    Ok(Registration {
+        day: required(args.day, "day")?,
+        part: required(args.part, "part")?,
+        solution: args.solution,
+    })
+}
+
+// the named Solution, else DayNN in the calling crate
+fn solution_type(day: u8, solution: Option<Path>) -> TokenStream2 {
+    match solution {
+        Some(path) => quote!(#path),
+        None => {
+            let ty = format_ident!("Day{:02}", day);
+            quote!(crate::#ty)
+        }
+    }
+}
+
+fn part_variant(part: u8) -> TokenStream2 {
+    match part {
+        1 => quote!(::aoc_core::Part::One),
+        _ => quote!(::aoc_core::Part::Two),
+    }
+}
+
+// test_part2_day4_part1_s1 for part 2 on day4-part1-s1.dat
+fn test_name(part: u8, file: &str) -> Ident {
+    let stem = file.strip_suffix(".dat").unwrap_or(file);
+    let stem: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format_ident!("test_part{}_{}", part, stem)
+}
+
+fn expand_sample_test(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let test = parse_sample_test(input)?;
+    let name = test_name(test.part, &test.file.value());
+    let solution = solution_type(test.day, test.solution);
+    let part = part_variant(test.part);
+    let ignore = test.ignore.map(|reason| quote!(#[ignore = #reason]));
+    let (day, part_number, file, expect) = (test.day, test.part, test.file, test.expect);
+    Ok(quote! {
+        #[test]
+        #ignore
+        fn #name() {
+            let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../input/", #file));
+            let answer = ::aoc_core::solve::<#solution>(#part, input)
+                .unwrap_or_else(|e| panic!("{}: {}", #file, e));
+            assert_eq!(
+                answer,
+                ::std::string::ToString::to_string(&(#expect)),
+                "day {} part {} on {}",
+                #day,
+                #part_number,
+                #file
+            );
+        }
+    })
+}
+
+// the parts an impl of Solution solves: part 1 always, part 2 when it overrides the default
+fn solved_parts(item: &ItemImpl) -> Vec<u8> {
+    let defines_part2 = item
+        .items
+        .iter()
+        .any(|item| matches!(item, ImplItem::Fn(function) if function.sig.ident == "part2"));
+    if defines_part2 {
+        vec![1, 2]
+    } else {
+        vec![1]
+    }
+}
+
+fn expand_aoc(args: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    match syn::parse2(item)? {
+        Item::Impl(item) => expand_solution(args, item),
+        Item::Fn(function) => expand_solver(args, function),
+        item => Err(Error::new_spanned(
+            item,
+            "#[aoc] goes on an `impl Solution for DayNN` block or a solver function",
+        )),
+    }
+}
+
+fn expand_solution(args: TokenStream2, item: ItemImpl) -> syn::Result<TokenStream2> {
+    if !args.is_empty() {
+        return Err(Error::new_spanned(
+            args,
+            "#[aoc] on an impl takes no arguments, the day comes from the Solution",
+        ));
+    }
+    let is_solution = item
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .is_some_and(|segment| segment.ident == "Solution");
+    if !is_solution {
+        return Err(Error::new_spanned(
+            &item.self_ty,
+            "#[aoc] goes on an `impl Solution for DayNN` block",
+        ));
+    }
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "a registered Solution cannot be generic",
+        ));
+    }
+
+    let solution = &item.self_ty;
+    let registrations = solved_parts(&item).into_iter().map(|part| {
+        let variant = part_variant(part);
+        let method = format_ident!("part{}", part);
+        quote! {
+            ::aoc_core::inventory::submit! {
+                ::aoc_core::registry::Solver {
+                    day: <#solution as ::aoc_core::Solution>::DAY,
+                    part: #variant,
+                    name: concat!(module_path!(), "::", stringify!(#solution), "::", stringify!(#method)),
+                    load: ::aoc_core::input::load::<#solution>,
+                    parse: ::aoc_core::registry::parse::<#solution>,
+                    solve: ::aoc_core::registry::#method::<#solution>,
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        #item
+
+        #(#registrations)*
+    })
+}
+
+// whether the solver returns a Result, whose error then converts into AocError
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
+fn expand_solver(args: TokenStream2, function: ItemFn) -> syn::Result<TokenStream2> {
+    if args.is_empty() {
+        return Err(Error::new_spanned(
+            &function.sig.ident,
+            "#[aoc] on a function needs the day and part, as #[aoc(day = 6, part = 1)]",
+        ));
+    }
+    let registration = parse_registration(args)?;
+    let signature = &function.sig;
+    match signature.inputs.first() {
+        Some(FnArg::Receiver(receiver)) => {
+            return Err(Error::new_spanned(
+                receiver,
+                "a solver takes the parsed input, not self",
+            ))
+        }
+        Some(FnArg::Typed(_)) if signature.inputs.len() == 1 => {}
+        _ => {
+            return Err(Error::new_spanned(
+                &signature.inputs,
+                "a solver takes exactly one argument, the parsed input",
+            ))
+        }
+    }
+    if !signature.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &signature.generics,
+            "a solver cannot be generic",
+        ));
+    }
+
+    let name = &signature.ident;
+    let solution = solution_type(registration.day, registration.solution);
+    let part = part_variant(registration.part);
+    let day = registration.day;
+    let mismatch = format!("day {} does not match the DAY of the Solution", day);
+    let answer = if returns_result(&signature.output) {
+        quote! {
+            answer
+                .map(|answer| ::std::string::ToString::to_string(&answer))
+                .map_err(::std::convert::Into::<::aoc_core::AocError>::into)
+        }
+    } else {
+        quote!(::std::result::Result::Ok(
+            ::std::string::ToString::to_string(&answer)
+        ))
+    };
+    Ok(quote! {
+        #function
+
+        const _: () = assert!(<#solution as ::aoc_core::Solution>::DAY == #day, #mismatch);
+
+        ::aoc_core::inventory::submit! {
+            ::aoc_core::registry::Solver {
+                day: #day,
+                part: #part,
+                name: concat!(module_path!(), "::", stringify!(#name)),
+                load: ::aoc_core::input::load::<#solution>,
+                parse: ::aoc_core::registry::parse::<#solution>,
+                solve: {
+                    fn solve(
+                        input: &::aoc_core::registry::Parsed,
+                    ) -> ::std::result::Result<::std::string::String, ::aoc_core::AocError> {
+                        let answer = #name(::aoc_core::registry::input::<#solution>(input));
+                        #answer
+                    }
+                    solve
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_name() {
+        assert_eq!(
+            test_name(2, "day4-part1-s1.dat"),
+            "test_part2_day4_part1_s1"
+        );
+        assert_eq!(test_name(1, "day12.extra"), "test_part1_day12_extra");
+    }
+
+    #[test]
+    fn test_parse_sample_test() {
+        let test = parse_sample_test(quote!(
+            day = 4,
+            part = 2,
+            file = "day4-part1-s1.dat",
+            expect = 9,
+            ignore = "slow",
+        ))
+        .unwrap();
+        assert_eq!((test.day, test.part), (4, 2));
+        assert_eq!(test.file.value(), "day4-part1-s1.dat");
+        assert_eq!(test.ignore.unwrap().value(), "slow");
+        assert!(test.solution.is_none());
+    }
+
+    #[test]
+    fn test_parse_sample_test_errors() {
+        let error = |tokens| parse_sample_test(tokens).err().unwrap().to_string();
+        assert_eq!(
+            error(quote!(day = 4, part = 3, file = "a.dat", expect = 9)),
+            "part must be 1 or 2"
+        );
+        assert_eq!(
+            error(quote!(day = 4, part = 1, expect = 9)),
+            "missing argument `file`"
+        );
+        assert_eq!(
+            error(quote!(day = "4", part = 1, file = "a.dat", expect = 9)),
+            "expected an integer literal"
+        );
+        assert!(error(quote!(day = 4, days = 1)).starts_with("unknown argument"));
+    }
+
+    #[test]
+    fn test_expand_solution_path() {
+        let tokens = expand_sample_test(quote!(
+            day = 3,
+            part = 1,
+            file = "day3-part1-s1.dat",
+            expect = 161,
+            solution = day_03::Day03,
+        ))
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("fn test_part1_day3_part1_s1"));
+        assert!(tokens.contains("solve :: < day_03 :: Day03 >"));
+        assert!(!tokens.contains("ignore"));
+    }
+
+    #[test]
+    fn test_expand_aoc() {
+        let tokens = expand_aoc(
+            quote!(),
+            quote!(
+                impl Solution for Day06 {
+                    const DAY: u8 = 6;
+                    type Input = GameBoard;
+                    type Answer = usize;
+                    fn parse(input: &str) -> Result<GameBoard, AocError> {
+                        GameBoard::parse(input)
+                    }
+                    fn part1(board: &GameBoard) -> Result<usize, AocError> {
+                        Ok(board.visited())
+                    }
+                }
+            ),
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("impl Solution for Day06"));
+        assert!(tokens.contains("day : < Day06 as :: aoc_core :: Solution > :: DAY"));
+        assert!(tokens.contains("parse :: < Day06 >"));
+        assert!(tokens.contains("solve : :: aoc_core :: registry :: part1 :: < Day06 >"));
+        assert!(!tokens.contains("part2"));
+
+        let tokens = expand_aoc(
+            quote!(),
+            quote!(
+                impl aoc_core::Solution for Day03 {
+                    fn part1(memory: &String) -> Result<i64, AocError> {
+                        todo!()
+                    }
+                    fn part2(memory: &String) -> Result<i64, AocError> {
+                        todo!()
+                    }
+                }
+            ),
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("registry :: part1 :: < Day03 >"));
+        assert!(tokens.contains("registry :: part2 :: < Day03 >"));
+        assert!(tokens.contains("part : :: aoc_core :: Part :: Two"));
+    }
+
+    #[test]
+    fn test_parse_registration() {
+        let registration = parse_registration(quote!(day = 6, part = 1)).unwrap();
+        assert_eq!((registration.day, registration.part), (6, 1));
+        let error = parse_registration(quote!(day = 6, part = 1, file = "a.dat"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "unknown argument, expected day, part, solution"
+        );
+    }
+
+    #[test]
+    fn test_expand_aoc_function() {
+        let tokens = expand_aoc(
+            quote!(day = 6, part = 1),
+            quote!(
+                fn visited(board: &GameBoard) -> usize {
+                    board.visited()
+                }
+            ),
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("fn visited (board : & GameBoard)"));
+        assert!(tokens.contains("< crate :: Day06 as :: aoc_core :: Solution > :: DAY == 6u8"));
+        assert!(tokens.contains("parse :: < crate :: Day06 >"));
+        assert!(tokens.contains("part : :: aoc_core :: Part :: One"));
+        assert!(!tokens.contains("map_err"));
+
+        let tokens = expand_aoc(
+            quote!(day = 3, part = 2, solution = day_03::Day03),
+            quote!(
+                fn scan(memory: &str) -> Result<i64, ParseError> {
+                    todo!()
+                }
+            ),
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("parse :: < day_03 :: Day03 >"));
+        assert!(tokens.contains("map_err"));
+    }
+
+    #[test]
+    fn test_expand_aoc_errors() {
+        let error = |args, item| expand_aoc(args, item).err().unwrap().to_string();
+        assert_eq!(
+            error(quote!(day = 1), quote!(impl Solution for Day01 {})),
+            "#[aoc] on an impl takes no arguments, the day comes from the Solution"
+        );
+        assert_eq!(
+            error(quote!(), quote!(impl Day01 {})),
+            "#[aoc] goes on an `impl Solution for DayNN` block"
+        );
+        assert_eq!(
+            error(
+                quote!(),
+                quote!(
+                    impl<T> Solution for Day01<T> {}
+                )
+            ),
+            "a registered Solution cannot be generic"
+        );
+        assert_eq!(
+            error(
+                quote!(),
+                quote!(
+                    struct Day01;
+                )
+            ),
+            "#[aoc] goes on an `impl Solution for DayNN` block or a solver function"
+        );
+        assert_eq!(
+            error(
+                quote!(),
+                quote!(
+                    fn solve(input: &str) -> u64 {
+                        0
+                    }
+                )
+            ),
+            "#[aoc] on a function needs the day and part, as #[aoc(day = 6, part = 1)]"
+        );
+        assert_eq!(
+            error(
+                quote!(day = 1, part = 1),
+                quote!(
+                    fn solve(&self) -> u64 {
+                        0
+                    }
+                )
+            ),
+            "a solver takes the parsed input, not self"
+        );
+        assert_eq!(
+            error(
+                quote!(day = 1, part = 1),
+                quote!(
+                    fn solve(a: &str, b: &str) -> u64 {
+                        0
+                    }
+                )
+            ),
+            "a solver takes exactly one argument, the parsed input"
+        );
+        assert_eq!(
+            error(
+                quote!(day = 1),
+                quote!(
+                    fn solve(a: &str) -> u64 {
+                        0
+                    }
+                )
+            ),
+            "missing argument `part`"
+        );
+    }
+}
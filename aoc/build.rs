@@ -0,0 +1,9 @@
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    // include_dir cannot tell cargo which files it read, so rebuild when an input changes
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        println!("cargo:rerun-if-changed=../input");
+    }
+}
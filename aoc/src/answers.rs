@@ -0,0 +1,321 @@
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use aoc_core::Part;
+
+use crate::input::InputName;
+
+// the README table is regenerated between these markers
+pub const README_BEGIN: &str = "<!-- answers:begin -->";
+pub const README_END: &str = "<!-- answers:end -->";
+// written at the top of the registry when it is saved
+const HEADER: &str = "# Known answers for each day, part and input file in input/\n\n";
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("invalid part {part} recorded for day {day}")]
+    InvalidPart { day: u8, part: u8 },
+    #[error("README is missing the {} and {} markers", README_BEGIN, README_END)]
+    MissingMarkers,
+}
+
+// the known answer for one day and part against one input file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+}
+
+impl AnswerRecord {
+    pub fn part(&self) -> Part {
+        Part::try_from(self.part).expect("parts are validated on load")
+    }
+
+    pub fn sample(&self) -> Option<u32> {
+        InputName::parse(&self.input).and_then(|name| name.sample)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    pub records: Vec<AnswerRecord>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = fs::read_to_string(path).map_err(|source| AnswersError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let answers: Answers = toml::from_str(&text).map_err(|source| AnswersError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        answers.validate()?;
+        Ok(answers)
+    }
+
+    fn validate(&self) -> Result<(), AnswersError> {
+        for record in self.records.iter() {
+            if Part::try_from(record.part).is_err() {
+                return Err(AnswersError::InvalidPart {
+                    day: record.day,
+                    part: record.part,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let text = toml::to_string(self).expect("answers always serialize");
+        fs::write(path, format!("{}{}", HEADER, text)).map_err(|source| AnswersError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    // set the answer for one day, part and input, a new record going after the others for that
+    // day and part
+    pub fn record(&mut self, day: u8, part: Part, input: &str, answer: &str) {
+        let key = (day, part.number());
+        if let Some(record) = self
+            .records
+            .iter_mut()
+            .find(|r| (r.day, r.part) == key && r.input == input)
+        {
+            record.answer = Some(answer.to_string());
+            return;
+        }
+        let at = self
+            .records
+            .iter()
+            .position(|r| (r.day, r.part) > key)
+            .unwrap_or(self.records.len());
+        self.records.insert(
+            at,
+            AnswerRecord {
+                day,
+                part: part.number(),
+                input: input.to_string(),
+                answer: Some(answer.to_string()),
+            },
+        );
+    }
+
+    pub fn expected(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.records
+            .iter()
+            .find(|r| r.day == day && r.part == part.number() && r.input == input)
+            .and_then(|r| r.answer.as_deref())
+    }
+
+    // markdown table of every recorded answer, samples labelled as such
+    pub fn readme_table(&self) -> String {
+        let rows: Vec<[String; 3]> = self
+            .records
+            .iter()
+            .filter_map(|record| {
+                let answer = record.answer.as_ref()?;
+                let part = match record.sample() {
+                    Some(1) => format!("Part {} - Sample", record.part),
+                    Some(n) => format!("Part {} - Sample {}", record.part, n),
+                    None => format!("Part {}", record.part),
+                };
+                Some([format!("Day {}", record.day), part, answer.clone()])
+            })
+            .collect();
+        let header = ["Day", "Part", "Solution"].map(String::from);
+        let mut widths = header.clone().map(|h| h.len().max(5));
+        for row in rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let mut table = String::new();
+        let write_row = |table: &mut String, row: &[String; 3]| {
+            table.push('|');
+            for (cell, width) in row.iter().zip(widths.iter()) {
+                write!(table, " {:<width$} |", cell, width = width).unwrap();
+            }
+            table.push('\n');
+        };
+        write_row(&mut table, &header);
+        write_row(&mut table, &widths.map(|w| "-".repeat(w)));
+        for row in rows.iter() {
+            write_row(&mut table, row);
+        }
+        table
+    }
+}
+
+// replace the table between the README markers, keeping the markers
+pub fn replace_readme_table(readme: &str, table: &str) -> Result<String, AnswersError> {
+    let begin = readme
+        .find(README_BEGIN)
+        .ok_or(AnswersError::MissingMarkers)?
+        + README_BEGIN.len();
+    let end = readme[begin..]
+        .find(README_END)
+        .ok_or(AnswersError::MissingMarkers)?
+        + begin;
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..begin],
+        table,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[[answer]]
+day = 1
+part = 1
+input = "day1-part1-s1.dat"
+answer = "11"
+
+[[answer]]
+day = 1
+part = 2
+input = "day1-part2.dat"
+answer = "23177084"
+
+[[answer]]
+day = 7
+part = 1
+input = "day7-part1.dat"
+"#;
+
+    #[test]
+    fn test_save_keeps_the_registry_format() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+        let answers = Answers::load(&path).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let saved = dir.path().join("answers.toml");
+        answers.save(&saved).unwrap();
+        assert_eq!(
+            fs::read_to_string(saved).unwrap(),
+            fs::read_to_string(path).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers: Answers = toml::from_str(ANSWERS).unwrap();
+        answers.record(7, Part::One, "day7-part1.dat", "3749");
+        answers.record(1, Part::One, "day1-part1.dat", "2057374");
+        answers.record(8, Part::Two, "day8-part1.dat", "34");
+        let records: Vec<(u8, u8, &str, Option<&str>)> = answers
+            .records
+            .iter()
+            .map(|r| (r.day, r.part, r.input.as_str(), r.answer.as_deref()))
+            .collect();
+        assert_eq!(
+            records,
+            [
+                (1, 1, "day1-part1-s1.dat", Some("11")),
+                (1, 1, "day1-part1.dat", Some("2057374")),
+                (1, 2, "day1-part2.dat", Some("23177084")),
+                (7, 1, "day7-part1.dat", Some("3749")),
+                (8, 2, "day8-part1.dat", Some("34")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(answers.records.len(), 3);
+        assert_eq!(answers.records[2].answer, None);
+        assert!(answers.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_part() {
+        let answers: Answers =
+            toml::from_str("[[answer]]\nday = 1\npart = 3\ninput = \"day1-part1.dat\"\n").unwrap();
+        assert!(matches!(
+            answers.validate(),
+            Err(AnswersError::InvalidPart { day: 1, part: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_expected() {
+        let answers: Answers = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(
+            answers.expected(1, Part::One, "day1-part1-s1.dat"),
+            Some("11")
+        );
+        assert_eq!(answers.expected(1, Part::Two, "day1-part1-s1.dat"), None);
+        assert_eq!(answers.expected(7, Part::One, "day7-part1.dat"), None);
+    }
+
+    #[test]
+    fn test_sample() {
+        let answers: Answers = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(answers.records[0].sample(), Some(1));
+        assert_eq!(answers.records[1].sample(), None);
+    }
+
+    #[test]
+    fn test_readme_table() {
+        let answers: Answers = toml::from_str(ANSWERS).unwrap();
+        let expect = "\
+| Day   | Part            | Solution |
+| ----- | --------------- | -------- |
+| Day 1 | Part 1 - Sample | 11       |
+| Day 1 | Part 2          | 23177084 |
+";
+        assert_eq!(answers.readme_table(), expect);
+    }
+
+    #[test]
+    fn test_replace_readme_table() {
+        let readme = format!(
+            "# Solutions\n{}\nold\n{}\n# Run\n",
+            README_BEGIN, README_END
+        );
+        let replaced = replace_readme_table(&readme, "| new |\n").unwrap();
+        assert_eq!(
+            replaced,
+            format!(
+                "# Solutions\n{}\n\n| new |\n\n{}\n# Run\n",
+                README_BEGIN, README_END
+            )
+        );
+        assert_eq!(
+            replace_readme_table(&replaced, "| new |\n").unwrap(),
+            replaced
+        );
+    }
+
+    #[test]
+    fn test_replace_readme_table_missing_markers() {
+        assert!(matches!(
+            replace_readme_table("# Solutions\n", "| new |\n"),
+            Err(AnswersError::MissingMarkers)
+        ));
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use aoc_core::{registry, AocError, Part, Solver};
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("{0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve => write!(f, "solve"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  stddev {:>10.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+// timing of one phase of one day and part
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: Part,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+// parse and solve samples of a registered solver
+fn time_phases(
+    solver: &Solver,
+    input: &str,
+    iterations: usize,
+) -> Result<(Vec<Duration>, Vec<Duration>), AocError> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = Vec::with_capacity(iterations);
+    let input = (solver.load)(input);
+    // one untimed warm up run, which also surfaces errors before timing
+    for iteration in 0..=iterations {
+        let start = Instant::now();
+        let input = black_box((solver.parse)(black_box(&input))?);
+        let parsed = start.elapsed();
+
+        let start = Instant::now();
+        let answer = (solver.solve)(&input)?;
+        let solved = start.elapsed();
+        black_box(answer);
+
+        if iteration > 0 {
+            parse.push(parsed);
+            solve.push(solved);
+        }
+    }
+    Ok((parse, solve))
+}
+
+// time parsing and solving one day and part separately over several iterations
+pub fn bench(
+    day: u8,
+    part: Part,
+    input: &str,
+    iterations: usize,
+) -> Result<Vec<Measurement>, AocError> {
+    let solver = registry::find(day, part).ok_or(AocError::NotImplemented { day, part })?;
+    let (parse, solve) = time_phases(solver, input, iterations.max(1))?;
+    Ok([(Phase::Parse, parse), (Phase::Solve, solve)]
+        .into_iter()
+        .map(|(phase, samples)| Measurement {
+            day,
+            part,
+            phase,
+            stats: Stats::from_samples(&samples).expect("at least one iteration"),
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub day: u8,
+    pub part: u8,
+    pub phase: Phase,
+    pub median_ns: u64,
+}
+
+// saved median timings to compare later runs against
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(rename = "timing", default)]
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let text = fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        toml::from_str(&text).map_err(|source| BaselineError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, toml::to_string(self)?).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn from_measurements(measurements: &[Measurement]) -> Self {
+        let entries = measurements
+            .iter()
+            .map(|m| BaselineEntry {
+                day: m.day,
+                part: m.part.number(),
+                phase: m.phase,
+                median_ns: m.stats.median.as_nanos() as u64,
+            })
+            .collect();
+        Self { entries }
+    }
+
+    // replace the entries for the measured days and parts, keeping the others
+    pub fn record(&mut self, measurements: &[Measurement]) {
+        for entry in Self::from_measurements(measurements).entries {
+            self.entries
+                .retain(|e| (e.day, e.part, e.phase) != (entry.day, entry.part, entry.phase));
+            self.entries.push(entry);
+        }
+        self.entries.sort_by_key(|e| (e.day, e.part, e.phase));
+    }
+
+    pub fn median(&self, day: u8, part: Part, phase: Phase) -> Option<Duration> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.part == part.number() && e.phase == phase)
+            .map(|e| Duration::from_nanos(e.median_ns))
+    }
+}
+
+// relative change of the median against the baseline, in percent
+pub fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0.0;
+    }
+    (current.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64() * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(4)]).unwrap();
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.mean, ms(4));
+        assert_eq!(stats.stddev.as_micros(), 1414);
+    }
+
+    #[test]
+    fn test_stats_odd_median() {
+        let stats = Stats::from_samples(&[ms(9), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let measurements = bench(1, Part::Two, "3 4\n4 3\n", 3).unwrap();
+        assert_eq!(measurements.len(), 2);
+        assert_eq!(measurements[0].phase, Phase::Parse);
+        assert_eq!(measurements[1].phase, Phase::Solve);
+        assert!(measurements
+            .iter()
+            .all(|m| m.day == 1 && m.part == Part::Two));
+    }
+
+    #[test]
+    fn test_bench_error() {
+        assert!(bench(1, Part::One, "3\n", 3).is_err());
+        assert!(matches!(
+            bench(26, Part::One, "", 3),
+            Err(AocError::NotImplemented { day: 26, .. })
+        ));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = Stats::from_samples(&[ms(2)]).unwrap();
+        let measurements = [Measurement {
+            day: 3,
+            part: Part::Two,
+            phase: Phase::Solve,
+            stats,
+        }];
+        let baseline = Baseline::from_measurements(&measurements);
+        let text = toml::to_string(&baseline).unwrap();
+        assert!(text.contains("phase = \"solve\""));
+        let loaded: Baseline = toml::from_str(&text).unwrap();
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.median(3, Part::Two, Phase::Solve), Some(ms(2)));
+        assert_eq!(loaded.median(3, Part::Two, Phase::Parse), None);
+    }
+
+    #[test]
+    fn test_baseline_record() {
+        let measurement = |day, ms_median| Measurement {
+            day,
+            part: Part::One,
+            phase: Phase::Parse,
+            stats: Stats::from_samples(&[ms(ms_median)]).unwrap(),
+        };
+        let mut baseline = Baseline::from_measurements(&[measurement(2, 5), measurement(1, 5)]);
+        baseline.record(&[measurement(2, 7)]);
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(baseline.entries[0].day, 1);
+        assert_eq!(baseline.median(2, Part::One, Phase::Parse), Some(ms(7)));
+    }
+
+    #[test]
+    fn test_change_percent() {
+        assert_eq!(change_percent(ms(100), ms(110)).round(), 10.0);
+        assert_eq!(change_percent(ms(100), ms(50)).round(), -50.0);
+        assert_eq!(change_percent(Duration::ZERO, ms(50)), 0.0);
+    }
+}
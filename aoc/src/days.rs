@@ -0,0 +1,140 @@
+use aoc_core::registry;
+use aoc_core::{AocError, Part, Report, ReportStatus};
+
+// link the day crates, whose #[aoc] Solutions register themselves with the runner
+use day_01 as _;
+use day_02 as _;
+use day_03 as _;
+use day_04 as _;
+use day_05 as _;
+use day_06 as _;
+
+// the days with a registered solver, in order
+pub fn days() -> Vec<u8> {
+    registry::days()
+}
+
+// dispatch a single day and part to the solver registered for it
+pub fn solve(day: u8, part: Part, input: &str) -> Result<String, AocError> {
+    match registry::find(day, part) {
+        Some(solver) => solver.run(input),
+        None => Err(AocError::NotImplemented { day, part }),
+    }
+}
+
+// solve a single day and part, timing each phase, for structured output
+pub fn report(day: u8, part: Part, input: &str, input_name: &str) -> Report {
+    match registry::find(day, part) {
+        Some(solver) => solver.report(input, input_name),
+        None => Report {
+            day,
+            part: part.number(),
+            input: input_name.to_string(),
+            status: ReportStatus::NotImplemented,
+            answer: None,
+            error: Some(AocError::NotImplemented { day, part }.to_string()),
+            parse_ns: None,
+            solve_ns: None,
+            warnings: Vec::new(),
+            parse_alloc: None,
+            solve_alloc: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // holds whichever days are linked, so adding one with `aoc new` keeps it passing
+    #[test]
+    fn test_days() {
+        let days = days();
+        assert!(!days.is_empty());
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(days.iter().all(|day| (1..=25).contains(day)));
+        for &day in days.iter() {
+            assert!(
+                registry::find(day, Part::One).is_some(),
+                "day {} has no part 1 solver",
+                day
+            );
+        }
+        let names: Vec<&str> = registry::solvers()
+            .iter()
+            .map(|solver| solver.name)
+            .collect();
+        assert!(names.contains(&"day_01::Day01::part1"));
+        assert!(names.contains(&"day_06::Day06::part1"));
+    }
+
+    #[test]
+    fn test_solve_day_1() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(solve(1, Part::One, input).unwrap(), "11");
+        assert_eq!(solve(1, Part::Two, input).unwrap(), "31");
+    }
+
+    #[test]
+    fn test_solve_day_3_joins_lines() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]t\nhen(mul(11,8)mul(8,5))";
+        assert_eq!(solve(3, Part::One, input).unwrap(), "161");
+    }
+
+    #[test]
+    fn test_solve_invalid_letter_map() {
+        assert!(matches!(
+            solve(4, Part::One, "XMAS\nXM"),
+            Err(AocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_solve_normalizes_pasted_input() {
+        let sample = |name| {
+            std::fs::read_to_string(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("../input")
+                    .join(name),
+            )
+            .unwrap()
+        };
+        let windows = |text: String| format!("\u{feff}{}", text.replace('\n', " \r\n"));
+        let grid = windows(sample("day4-part1-s1.dat"));
+        assert_eq!(solve(4, Part::One, &grid).unwrap(), "18");
+        let queue = windows(sample("day5-part1-s1.dat"));
+        assert_eq!(solve(5, Part::One, &queue).unwrap(), "143");
+        let report = report(5, Part::One, &queue, "pasted.dat");
+        assert_eq!(report.warnings[0], "input: removed a byte order mark");
+    }
+
+    #[test]
+    fn test_solve_not_implemented() {
+        assert!(matches!(
+            solve(6, Part::Two, "..^.."),
+            Err(AocError::NotImplemented {
+                day: 6,
+                part: Part::Two
+            })
+        ));
+        assert!(matches!(
+            solve(26, Part::One, ""),
+            Err(AocError::NotImplemented {
+                day: 26,
+                part: Part::One
+            })
+        ));
+    }
+
+    #[test]
+    fn test_report() {
+        let report = report(2, Part::One, "1 2 3\n", "reports.dat");
+        assert_eq!(report.status, ReportStatus::Solved);
+        assert_eq!(report.answer.as_deref(), Some("1"));
+        assert_eq!(report.input, "reports.dat");
+        assert_eq!(
+            super::report(26, Part::Two, "", "-").status,
+            ReportStatus::NotImplemented
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+use include_dir::{include_dir, Dir};
+
+use aoc_crypt::{encrypted_path, CryptError, Key};
+
+// the workspace's input/ directory as it was when the runner was built
+static INPUTS: Dir = include_dir!("$CARGO_MANIFEST_DIR/../input");
+const SOURCE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../input");
+
+// SOURCE resolved on disk, looked up once; None outside the checkout the runner was built in
+fn canonical_source() -> Option<&'static Path> {
+    static CANONICAL: OnceLock<Option<PathBuf>> = OnceLock::new();
+    CANONICAL
+        .get_or_init(|| fs::canonicalize(SOURCE).ok())
+        .as_deref()
+}
+
+// whether dir is the directory the inputs were embedded from: the runner's default input
+// directory, input/ by the path it was built from, or the same directory under another name
+pub fn embeds(dir: &Path) -> bool {
+    let dir: PathBuf = dir
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect();
+    if dir == Path::new("input") || dir == Path::new(SOURCE) {
+        return true;
+    }
+    match (fs::canonicalize(&dir), canonical_source()) {
+        (Ok(dir), Some(source)) => dir == source,
+        _ => false,
+    }
+}
+
+// the embedded file standing in for path, which is in the embedded directory
+fn file_for(path: &Path) -> Option<&'static include_dir::File<'static>> {
+    if !embeds(path.parent()?) {
+        return None;
+    }
+    let name = Path::new(path.file_name()?);
+    INPUTS
+        .get_file(name)
+        .or_else(|| INPUTS.get_file(encrypted_path(name)))
+}
+
+// an embedded .enc input in clear text, or the kind and message of the error decrypting it
+type Decrypted = Result<String, (io::ErrorKind, String)>;
+
+fn failure(e: CryptError) -> (io::ErrorKind, String) {
+    let e = io::Error::from(e);
+    (e.kind(), e.to_string())
+}
+
+// every embedded .enc input, decrypted with key
+fn decrypt_all(key: Result<Key, CryptError>) -> HashMap<&'static Path, Decrypted> {
+    let key = key.map_err(failure);
+    INPUTS
+        .files()
+        .filter(|file| aoc_crypt::is_encrypted(file.path()))
+        .map(|file| {
+            let input = key.as_ref().map_err(Clone::clone).and_then(|key| {
+                let plaintext = key.decrypt(file.contents(), file.path()).map_err(failure)?;
+                String::from_utf8(plaintext)
+                    .map_err(|_| failure(CryptError::Utf8(file.path().into())))
+            });
+            (file.path(), input)
+        })
+        .collect()
+}
+
+// the embedded input standing in for the file at path; None when path is not an embedded input.
+// The first read of an .enc copy finds the key nearest path and decrypts every embedded input at
+// once, so later reads, and the timings taken after them, neither look for the key nor decrypt
+pub fn read(path: &Path) -> Option<io::Result<String>> {
+    static DECRYPTED: OnceLock<HashMap<&'static Path, Decrypted>> = OnceLock::new();
+    let file = file_for(path)?;
+    if !aoc_crypt::is_encrypted(file.path()) {
+        let input = file.contents_utf8().map(str::to_string).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: embedded input is not UTF-8", file.path().display()),
+            )
+        });
+        return Some(input);
+    }
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let input = DECRYPTED
+        .get_or_init(|| decrypt_all(Key::find(dir)))
+        .get(file.path())
+        .cloned()
+        .expect("every embedded .enc input is decrypted together");
+    Some(input.map_err(|(kind, message)| io::Error::new(kind, message)))
+}
+
+pub fn exists(path: &Path) -> bool {
+    file_for(path).is_some()
+}
+
+// the names of the embedded files, encrypted ones with their .enc extension
+pub fn file_names() -> impl Iterator<Item = String> {
+    INPUTS
+        .files()
+        .map(|file| file.path().to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embeds() {
+        assert!(embeds(Path::new("input")));
+        assert!(embeds(Path::new("./input")));
+        assert!(embeds(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../input")
+        ));
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        assert!(embeds(&workspace.join("input")));
+        // the same directory by another name, from aoc/ where the tests run
+        assert!(embeds(Path::new("../input")));
+        assert!(!embeds(Path::new("elsewhere")));
+        assert!(!embeds(tempfile::tempdir().unwrap().path()));
+    }
+
+    #[test]
+    fn test_read() {
+        let input = read(Path::new("input/day1-part1-s1.dat")).unwrap().unwrap();
+        assert!(input.starts_with("3   4\n"));
+        assert!(read(Path::new("input/day99-part1.dat")).is_none());
+        assert!(read(Path::new("elsewhere/day1-part1-s1.dat")).is_none());
+    }
+
+    #[test]
+    fn test_decrypt_all() {
+        let encrypted = file_names().filter(|name| name.ends_with(".enc")).count();
+        let decrypted = decrypt_all(Err(CryptError::MissingKey));
+        assert_eq!(decrypted.len(), encrypted);
+        assert!(decrypted
+            .values()
+            .all(|input| matches!(input, Err((io::ErrorKind::NotFound, _)))));
+        let decrypted = decrypt_all(Ok(Key::generate()));
+        assert!(decrypted
+            .values()
+            .all(|input| matches!(input, Err((io::ErrorKind::InvalidData, _)))));
+    }
+
+    #[test]
+    fn test_exists() {
+        assert!(exists(Path::new("input/day6-part1-s1.dat")));
+        // real inputs are embedded encrypted
+        assert!(exists(Path::new("input/day6-part1.dat")));
+        assert!(!exists(Path::new("input/day99-part1.dat")));
+    }
+
+    #[test]
+    fn test_file_names() {
+        assert!(file_names().any(|name| name == "day1-part1-s1.dat"));
+    }
+}
@@ -0,0 +1,188 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use aoc_core::Part;
+
+// path used to read puzzle input from stdin
+pub const STDIN: &str = "-";
+
+// input/dayN-partM.dat, falling back to the part 1 input shared by both parts
+pub fn default_input_path(input_dir: &Path, day: u8, part: Part) -> PathBuf {
+    let path = input_dir.join(format!("day{}-part{}.dat", day, part));
+    if input_exists(&path) {
+        return path;
+    }
+    input_dir.join(format!("day{}-part1.dat", day))
+}
+
+// input/dayN-partM-s1.dat, falling back to the part 1 sample like default_input_path
+pub fn default_sample_path(input_dir: &Path, day: u8, part: Part) -> PathBuf {
+    let path = input_dir.join(format!("day{}-part{}-s1.dat", day, part));
+    if input_exists(&path) {
+        return path;
+    }
+    input_dir.join(format!("day{}-part1-s1.dat", day))
+}
+
+// parts of an input file name following the dayN-partM(-sK).dat convention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InputName {
+    pub day: u8,
+    pub part: u8,
+    pub sample: Option<u32>,
+}
+
+impl InputName {
+    pub fn parse(file_name: &str) -> Option<Self> {
+        let stem = file_name.strip_suffix(".dat")?;
+        let rest = stem.strip_prefix("day")?;
+        let (day, rest) = rest.split_once("-part")?;
+        let (part, sample) = match rest.split_once("-s") {
+            Some((part, sample)) => (part, Some(sample.parse().ok()?)),
+            None => (rest, None),
+        };
+        Some(Self {
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            sample,
+        })
+    }
+}
+
+// the names of the files in the input directory; built with embed-inputs, the embedded input/
+// stands in for the directory it was read from
+fn file_names(input_dir: &Path) -> io::Result<Vec<String>> {
+    #[cfg(feature = "embed-inputs")]
+    if crate::embedded::embeds(input_dir) {
+        return Ok(crate::embedded::file_names().collect());
+    }
+    fs::read_dir(input_dir)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect()
+}
+
+// every input file in the directory that follows the naming convention, sorted by name;
+// encrypted inputs are listed under their clear text name
+pub fn discover_inputs(input_dir: &Path) -> io::Result<Vec<(String, InputName)>> {
+    let mut inputs = Vec::new();
+    for mut file_name in file_names(input_dir)? {
+        if let Some(plain) = file_name.strip_suffix(&format!(".{}", aoc_crypt::EXTENSION)) {
+            file_name = plain.to_string();
+        }
+        if let Some(name) = InputName::parse(&file_name) {
+            inputs.push((file_name, name));
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+// whether the input at path, or its encrypted copy, exists, on disk or embedded
+pub fn input_exists(path: &Path) -> bool {
+    #[cfg(feature = "embed-inputs")]
+    if crate::embedded::exists(path) {
+        return true;
+    }
+    aoc_crypt::input_exists(path)
+}
+
+// read an input from stdin, or from its file; built with embed-inputs, the files of input/ are
+// read from the binary instead
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+    #[cfg(feature = "embed-inputs")]
+    if let Some(input) = crate::embedded::read(path) {
+        return input;
+    }
+    aoc_crypt::read_input(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../input")
+    }
+
+    #[test]
+    fn test_default_input_path() {
+        let dir = input_dir();
+        assert_eq!(
+            default_input_path(&dir, 1, Part::Two),
+            dir.join("day1-part2.dat")
+        );
+    }
+
+    #[test]
+    fn test_default_input_path_falls_back_to_part_1() {
+        let dir = input_dir();
+        assert_eq!(
+            default_input_path(&dir, 2, Part::Two),
+            dir.join("day2-part1.dat")
+        );
+    }
+
+    #[test]
+    fn test_default_sample_path() {
+        let dir = input_dir();
+        assert_eq!(
+            default_sample_path(&dir, 3, Part::Two),
+            dir.join("day3-part2-s1.dat")
+        );
+        assert_eq!(
+            default_sample_path(&dir, 4, Part::Two),
+            dir.join("day4-part1-s1.dat")
+        );
+        // the tests run in aoc/, where input/ is found only among the embedded files
+        #[cfg(feature = "embed-inputs")]
+        assert_eq!(
+            default_sample_path(Path::new("input"), 3, Part::Two),
+            Path::new("input/day3-part2-s1.dat")
+        );
+    }
+
+    #[test]
+    fn test_input_name() {
+        assert_eq!(
+            InputName::parse("day4-part1-s1.dat"),
+            Some(InputName {
+                day: 4,
+                part: 1,
+                sample: Some(1)
+            })
+        );
+        assert_eq!(
+            InputName::parse("day12-part2.dat"),
+            Some(InputName {
+                day: 12,
+                part: 2,
+                sample: None
+            })
+        );
+        assert_eq!(InputName::parse("day4-part1.txt"), None);
+        assert_eq!(InputName::parse("day4.dat"), None);
+        assert_eq!(InputName::parse("dayx-part1.dat"), None);
+    }
+
+    #[test]
+    fn test_discover_inputs() {
+        let inputs = discover_inputs(&input_dir()).unwrap();
+        assert!(inputs
+            .iter()
+            .any(|(file_name, _)| file_name == "day6-part1.dat"));
+        assert!(inputs.iter().all(|(_, name)| name.day >= 1));
+    }
+
+    #[test]
+    fn test_read_input() {
+        let input = read_input(&input_dir().join("day1-part1-s1.dat")).unwrap();
+        assert_eq!(input.lines().count(), 6);
+        assert!(input.starts_with("3   4\n"));
+    }
+}
@@ -0,0 +1,13 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+#[cfg(feature = "embed-inputs")]
+pub mod embedded;
+pub mod input;
+pub mod limits;
+pub mod logging;
+pub mod scaffold;
+pub mod site;
+pub mod summary;
+pub mod verify;
+pub mod watch;
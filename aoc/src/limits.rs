@@ -0,0 +1,353 @@
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::{allocations, Part, Report, ReportStatus};
+
+use crate::days;
+use crate::input::{read_input, STDIN};
+
+// how often a limited solution is checked for having finished
+const POLL: Duration = Duration::from_millis(5);
+
+// the subcommand the runner starts itself with to solve one part under limits
+pub const ISOLATED: &str = "isolated";
+
+// what Rust prints before aborting when an allocation fails
+const ALLOCATION_FAILED: &str = "memory allocation of";
+
+// resource ceilings for one solution; None leaves that resource unlimited
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    // bytes of address space
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.memory.is_none()
+    }
+}
+
+// "500ms", "10s" or "2m"; a bare number is seconds
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let (number, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration `{}`, expected e.g. 500ms, 10s or 2m", s))?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(number)),
+        "" | "s" => Ok(Duration::from_secs(number)),
+        "m" => Ok(Duration::from_secs(number * 60)),
+        _ => Err(format!(
+            "unknown duration unit `{}`, expected ms, s or m",
+            unit
+        )),
+    }
+}
+
+// "64K", "512M" or "2G" in powers of 1024; a bare number is bytes
+pub fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (number, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid size `{}`, expected e.g. 64K, 512M or 2G", s))?;
+    let shift = match unit.to_ascii_uppercase().as_str() {
+        "" => 0,
+        "K" => 10,
+        "M" => 20,
+        "G" => 30,
+        _ => return Err(format!("unknown size unit `{}`, expected K, M or G", unit)),
+    };
+    number
+        .checked_mul(1 << shift)
+        .ok_or_else(|| format!("size `{}` is too large", s))
+}
+
+pub fn format_size(bytes: u64) -> String {
+    for (unit, shift) in [("G", 30), ("M", 20), ("K", 10)] {
+        if bytes >= 1 << shift && bytes.is_multiple_of(1 << shift) {
+            return format!("{}{}", bytes >> shift, unit);
+        }
+    }
+    bytes.to_string()
+}
+
+// where solutions run: in this process, or each in a child process held to limits
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Runner {
+    #[default]
+    InProcess,
+    // program is this runner's executable, started with the isolated subcommand; verbose is
+    // the number of -v it passes on
+    Isolated {
+        program: PathBuf,
+        limits: Limits,
+        verbose: u8,
+    },
+}
+
+impl Runner {
+    pub fn new(limits: Limits, verbose: u8) -> io::Result<Self> {
+        if limits.is_unlimited() {
+            return Ok(Runner::InProcess);
+        }
+        Ok(Runner::Isolated {
+            program: std::env::current_exe()?,
+            limits,
+            verbose,
+        })
+    }
+
+    // solve one day and part like days::report, within the limits
+    pub fn report(&self, day: u8, part: Part, input: &str, input_name: &str) -> Report {
+        match self {
+            Runner::InProcess => days::report(day, part, input, input_name),
+            Runner::Isolated {
+                program,
+                limits,
+                verbose,
+            } => run_isolated(program, *limits, *verbose, day, part, input, input_name),
+        }
+    }
+}
+
+fn stopped(day: u8, part: Part, input_name: &str, status: ReportStatus, error: String) -> Report {
+    Report {
+        day,
+        part: part.number(),
+        input: input_name.to_string(),
+        status,
+        answer: None,
+        error: Some(error),
+        parse_ns: None,
+        solve_ns: None,
+        warnings: Vec::new(),
+        parse_alloc: None,
+        solve_alloc: None,
+    }
+}
+
+fn exceeded_memory(day: u8, part: Part, input_name: &str, memory: Option<u64>) -> Report {
+    let limit = memory.map_or_else(|| "the system".to_string(), format_size);
+    let error = format!("exceeded the memory limit of {}", limit);
+    stopped(day, part, input_name, ReportStatus::ExceededMemory, error)
+}
+
+fn read_all(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+// the line of a child's stderr that says why it stopped, skipping the backtrace hint
+fn last_error(stderr: &str) -> Option<&str> {
+    stderr
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty() && !line.starts_with("note:"))
+}
+
+// start program with the isolated subcommand, feed it the input and wait for its report,
+// killing it once the timeout passes; with verbose the child's events are copied to stderr
+pub fn run_isolated(
+    program: &Path,
+    limits: Limits,
+    verbose: u8,
+    day: u8,
+    part: Part,
+    input: &str,
+    input_name: &str,
+) -> Report {
+    let mut command = Command::new(program);
+    command
+        .arg(ISOLATED)
+        .args(["--day", &day.to_string(), "--part", &part.to_string()])
+        .args(["--input-name", input_name]);
+    if let Some(memory) = limits.memory {
+        command.args(["--memory", &memory.to_string()]);
+    }
+    if verbose > 0 {
+        command.arg(format!("-{}", "v".repeat(verbose.into())));
+    }
+    if allocations::is_enabled() {
+        command.arg("--profile");
+    }
+    let spawned = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            let error = format!("{}: {}", program.display(), e);
+            return stopped(day, part, input_name, ReportStatus::Failed, error);
+        }
+    };
+
+    // feed and drain the pipes on their own threads so a full pipe never stalls the child
+    let stdin = child.stdin.take();
+    let input = input.to_string();
+    let writer = thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+    });
+    let stdout = child.stdout.take().map(read_all);
+    let stderr = child.stderr.take().map(read_all);
+
+    let start = Instant::now();
+    let exit = loop {
+        match child.try_wait() {
+            Ok(Some(exit)) => break Some(exit),
+            Ok(None)
+                if limits
+                    .timeout
+                    .is_some_and(|timeout| start.elapsed() >= timeout) =>
+            {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            Ok(None) => thread::sleep(POLL),
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                let error = format!("{}: {}", program.display(), e);
+                return stopped(day, part, input_name, ReportStatus::Failed, error);
+            }
+        }
+    };
+    let _ = writer.join();
+    let stdout = stdout
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    let stderr = stderr
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    if verbose > 0 {
+        eprint!("{}", stderr);
+    }
+
+    let Some(exit) = exit else {
+        let timeout = limits.timeout.unwrap_or_default();
+        let error = format!("timed out after {:?}", timeout);
+        return stopped(day, part, input_name, ReportStatus::TimedOut, error);
+    };
+    if let Ok(report) = serde_json::from_str::<Report>(stdout.trim()) {
+        return report;
+    }
+    if stderr.contains(ALLOCATION_FAILED) {
+        return exceeded_memory(day, part, input_name, limits.memory);
+    }
+    let error = last_error(&stderr).map_or_else(|| exit.to_string(), str::to_string);
+    stopped(day, part, input_name, ReportStatus::Failed, error)
+}
+
+#[cfg(unix)]
+fn limit_memory(bytes: u64) -> io::Result<()> {
+    rlimit::setrlimit(rlimit::Resource::AS, bytes, bytes)
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_bytes: u64) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "memory limits are only supported on unix",
+    ))
+}
+
+// the isolated subcommand: cap this process's memory, solve the input on stdin and print the
+// report as JSON for run_isolated to read
+pub fn isolated_main(day: u8, part: Part, input_name: &str, memory: Option<u64>) -> bool {
+    if let Some(bytes) = memory {
+        if let Err(e) = limit_memory(bytes) {
+            eprintln!("limiting memory to {}: {}", format_size(bytes), e);
+            return false;
+        }
+    }
+    let input = match read_input(Path::new(STDIN)) {
+        Ok(input) => input,
+        // reading reserves its buffer fallibly, so running out shows up here rather than as an abort
+        Err(e) if e.kind() == io::ErrorKind::OutOfMemory => {
+            println!(
+                "{}",
+                exceeded_memory(day, part, input_name, memory).to_json()
+            );
+            return true;
+        }
+        Err(e) => {
+            eprintln!("reading the input: {}", e);
+            return false;
+        }
+    };
+    println!("{}", days::report(day, part, &input, input_name).to_json());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("ten").is_err());
+        assert!(parse_duration("3h").is_err());
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("64K"), Ok(64 << 10));
+        assert_eq!(parse_size("512m"), Ok(512 << 20));
+        assert_eq!(parse_size("2G"), Ok(2 << 30));
+        assert!(parse_size("2T").is_err());
+        assert!(parse_size("M").is_err());
+        assert!(parse_size("99999999999G").is_err());
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512 << 20), "512M");
+        assert_eq!(format_size(2 << 30), "2G");
+        assert_eq!(format_size(1536), "1536");
+        assert_eq!(format_size(3 << 10), "3K");
+    }
+
+    #[test]
+    fn test_last_error() {
+        let stderr =
+            "thread 'main' panicked at src/lib.rs:3:5:\nboom\nnote: run with `RUST_BACKTRACE=1`\n";
+        assert_eq!(last_error(stderr), Some("boom"));
+        assert_eq!(last_error("\n"), None);
+    }
+
+    #[test]
+    fn test_runner_without_limits_runs_in_process() {
+        assert_eq!(
+            Runner::new(Limits::default(), 0).unwrap(),
+            Runner::InProcess
+        );
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(1)),
+            memory: None,
+        };
+        assert!(matches!(
+            Runner::new(limits, 0).unwrap(),
+            Runner::Isolated { .. }
+        ));
+    }
+}
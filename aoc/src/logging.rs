@@ -0,0 +1,39 @@
+use std::io::{self, IsTerminal};
+
+use tracing::Level;
+
+// the most detailed events each -v shows on stderr; none without -v, where the runner prints
+// the warnings of each report itself
+pub fn level(verbose: u8) -> Option<Level> {
+    match verbose {
+        0 => None,
+        1 => Some(Level::INFO),
+        2 => Some(Level::DEBUG),
+        _ => Some(Level::TRACE),
+    }
+}
+
+// send events at the level chosen by -v to stderr, keeping stdout for answers
+pub fn init(verbose: u8) {
+    if let Some(level) = level(verbose) {
+        tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .with_target(false)
+            .init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), None);
+        assert_eq!(level(1), Some(Level::INFO));
+        assert_eq!(level(2), Some(Level::DEBUG));
+        assert_eq!(level(5), Some(Level::TRACE));
+    }
+}
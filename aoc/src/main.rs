@@ -0,0 +1,738 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, Subcommand};
+
+use aoc::answers::{replace_readme_table, Answers};
+use aoc::bench::{bench, change_percent, Baseline};
+use aoc::days;
+use aoc::input::{default_input_path, read_input};
+use aoc::limits::{isolated_main, parse_duration, parse_size, Limits, Runner, ISOLATED};
+use aoc::logging;
+use aoc::scaffold::new_day;
+use aoc::site::{fetch, submit};
+use aoc::summary::{all_jobs, run_all, table};
+use aoc::verify::{verify, Status};
+use aoc::watch::Watch;
+use aoc_client::{Cache, Client, Session, Verdict, DEFAULT_URL};
+use aoc_core::allocations::{self, CountingAllocator};
+use aoc_core::{Allocations, AocError, OutputFormat, Part, Report, ReportStatus};
+
+// counts nothing until --profile enables it
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    /// Log phase timings and warnings to stderr; -vv adds debug events and -vvv traces
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run one, several or all solutions and print the answers
+    Run {
+        /// Day to run, all days when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to run, both parts when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin; defaults to the day's file in the input directory
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Directory holding the dayN-partM.dat input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Print answers as text, or every result with timings and warnings as JSON
+        #[arg(long, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Stop each solution that runs longer than this, e.g. 500ms, 10s or 2m
+        #[arg(long, value_parser = parse_duration)]
+        timeout: Option<Duration>,
+        /// Cap the memory of each solution, e.g. 512M or 2G
+        #[arg(long, value_parser = parse_size)]
+        memory: Option<u64>,
+        /// Count the allocations, bytes allocated and peak memory of parsing and solving
+        #[arg(long)]
+        profile: bool,
+    },
+    /// Time parsing and each part separately and compare against a saved baseline
+    Bench {
+        /// Day to benchmark, all days when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to benchmark, both parts when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Timed iterations of each phase
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Directory holding the dayN-partM.dat input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Baseline file to compare against
+        #[arg(long, default_value = "bench-baseline.toml")]
+        baseline: PathBuf,
+        /// Record this run's medians in the baseline file
+        #[arg(long)]
+        save_baseline: bool,
+        /// Flag medians slower than the baseline by more than this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check every recorded answer against its input file
+    Verify {
+        /// Only verify this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Answer registry
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory holding the input files named in the registry
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Regenerate the solutions table in this README from the registry
+        #[arg(long)]
+        update_readme: Option<PathBuf>,
+    },
+    /// Run every day and part concurrently and print a table checked against the recorded answers
+    Summary {
+        /// Answer registry
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory holding the dayN-partM.dat input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Worker threads, one per CPU when omitted
+        #[arg(short, long)]
+        jobs: Option<usize>,
+        /// Stop each solution that runs longer than this, e.g. 500ms, 10s or 2m
+        #[arg(long, value_parser = parse_duration)]
+        timeout: Option<Duration>,
+        /// Cap the memory of each solution, e.g. 512M or 2G
+        #[arg(long, value_parser = parse_size)]
+        memory: Option<u64>,
+    },
+    /// Rebuild and rerun one part on its sample and input whenever its crate or inputs change
+    Watch {
+        /// Day to watch
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to rerun
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer registry holding the expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory holding the dayN-partM.dat input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Workspace root
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Solve one part read from stdin and print its JSON report, run by the other commands to
+    /// hold a solution to --timeout and --memory
+    #[command(name = ISOLATED, hide = true)]
+    Isolated {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long)]
+        input_name: String,
+        /// Bytes of address space
+        #[arg(long)]
+        memory: Option<u64>,
+        #[arg(long)]
+        profile: bool,
+    },
+    /// Download a day's puzzle input into the input directory, encrypted when a key is found
+    Fetch {
+        /// Day to fetch
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory to write dayN-part1.dat to
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Replace an input that is already there
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Submit an answer and record it in the answer registry when it is accepted
+    Submit {
+        /// Day to submit
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to submit
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to send, solved from the day's input when omitted
+        #[arg(long)]
+        answer: Option<String>,
+        /// Answer registry to record accepted answers in
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory holding the dayN-partM.dat input files
+        #[arg(long, default_value = "input")]
+        input_dir: PathBuf,
+        /// Least time between two answers, e.g. 60s or 2m
+        #[arg(long, value_parser = parse_duration, default_value = "60s")]
+        throttle: Duration,
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Create a crate for a new day and register it in the workspace and runner
+    New {
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Workspace root
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct SiteArgs {
+    /// Root of the puzzle site
+    #[arg(long, default_value = DEFAULT_URL)]
+    url: String,
+    /// Directory caching fetched inputs, submitted answers and when answers were last sent
+    #[arg(long, default_value = ".aoc-cache")]
+    cache: PathBuf,
+}
+
+// the requested day and part, or every one of them when omitted
+fn selection(day: Option<u8>, part: Option<u8>) -> (Vec<u8>, Vec<Part>) {
+    let days: Vec<u8> = day.map_or_else(days::days, |day| vec![day]);
+    let parts: Vec<Part> = part.map_or_else(
+        || Part::ALL.to_vec(),
+        |part| vec![Part::try_from(part).expect("part is validated by clap")],
+    );
+    (days, parts)
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    input_dir: PathBuf,
+    format: OutputFormat,
+    limits: Limits,
+    verbose: u8,
+) -> bool {
+    let runner = match Runner::new(limits, verbose) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let (days, parts) = selection(day, part);
+    // parts sharing an input file, or stdin, read it only once
+    let mut inputs: HashMap<PathBuf, String> = HashMap::new();
+    let mut reports = Vec::new();
+    for &day in days.iter() {
+        for &part in parts.iter() {
+            let path = input
+                .clone()
+                .unwrap_or_else(|| default_input_path(&input_dir, day, part));
+            let input_name = path.display().to_string();
+            if !inputs.contains_key(&path) {
+                match read_input(&path) {
+                    Ok(input) => {
+                        inputs.insert(path.clone(), input);
+                    }
+                    Err(e) => {
+                        reports.push(Report {
+                            day,
+                            part: part.number(),
+                            error: Some(format!("{}: {}", input_name, e)),
+                            input: input_name,
+                            status: ReportStatus::Failed,
+                            answer: None,
+                            parse_ns: None,
+                            solve_ns: None,
+                            warnings: Vec::new(),
+                            parse_alloc: None,
+                            solve_alloc: None,
+                        });
+                        continue;
+                    }
+                }
+            }
+            reports.push(runner.report(day, part, &inputs[&path], &input_name));
+        }
+    }
+
+    match format {
+        OutputFormat::Text => {
+            for report in reports.iter() {
+                // with -v the warnings were already logged as events
+                if verbose == 0 {
+                    for warning in report.warnings.iter() {
+                        eprintln!(
+                            "Day {} Part {}: warning: {}",
+                            report.day, report.part, warning
+                        );
+                    }
+                }
+                match (&report.answer, &report.error) {
+                    (Some(answer), _) => {
+                        println!("Day {} Part {}: {}", report.day, report.part, answer)
+                    }
+                    (None, Some(e)) => eprintln!("Day {} Part {}: {}", report.day, report.part, e),
+                    (None, None) => {}
+                }
+                print_profile("parse", report.parse_ns, report.parse_alloc);
+                print_profile("solve", report.solve_ns, report.solve_alloc);
+            }
+        }
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&reports).expect("reports always serialize");
+            println!("{}", json);
+        }
+    }
+    reports.iter().all(|report| !report.status.is_failure())
+}
+
+// one phase's time and allocations under run --profile
+fn print_profile(phase: &str, nanoseconds: Option<u64>, allocations: Option<Allocations>) {
+    if let (Some(nanoseconds), Some(allocations)) = (nanoseconds, allocations) {
+        println!(
+            "  {}  {:>10.2?}  {}",
+            phase,
+            Duration::from_nanos(nanoseconds),
+            allocations
+        );
+    }
+}
+
+struct BenchOptions {
+    iterations: usize,
+    input_dir: PathBuf,
+    baseline: PathBuf,
+    save_baseline: bool,
+    threshold: f64,
+}
+
+fn run_bench(day: Option<u8>, part: Option<u8>, options: BenchOptions) -> bool {
+    let (days, parts) = selection(day, part);
+    let mut baseline = if options.baseline.exists() {
+        match Baseline::load(&options.baseline) {
+            Ok(baseline) => baseline,
+            Err(e) => {
+                eprintln!("{}", e);
+                return false;
+            }
+        }
+    } else {
+        Baseline::default()
+    };
+
+    let mut measurements = Vec::new();
+    let mut success = true;
+    for &day in days.iter() {
+        for &part in parts.iter() {
+            let path = default_input_path(&options.input_dir, day, part);
+            let input = match read_input(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Day {} Part {}: {}: {}", day, part, path.display(), e);
+                    success = false;
+                    continue;
+                }
+            };
+            match bench(day, part, &input, options.iterations) {
+                Ok(mut results) => measurements.append(&mut results),
+                Err(e @ AocError::NotImplemented { .. }) => {
+                    eprintln!("Day {} Part {}: {}", day, part, e);
+                }
+                Err(e) => {
+                    eprintln!("Day {} Part {}: {}", day, part, e);
+                    success = false;
+                }
+            }
+        }
+    }
+
+    let mut regressions = 0;
+    for m in measurements.iter() {
+        let comparison = match baseline.median(m.day, m.part, m.phase) {
+            Some(median) => {
+                let change = change_percent(median, m.stats.median);
+                let flag = if change > options.threshold {
+                    regressions += 1;
+                    "  REGRESSION"
+                } else {
+                    ""
+                };
+                format!("  {:>+7.1}% vs baseline{}", change, flag)
+            }
+            None => String::new(),
+        };
+        println!(
+            "Day {} Part {} {}  {}{}",
+            m.day, m.part, m.phase, m.stats, comparison
+        );
+    }
+    if regressions > 0 {
+        println!(
+            "{} regression(s) slower than the baseline by more than {}%",
+            regressions, options.threshold
+        );
+    }
+
+    if options.save_baseline {
+        baseline.record(&measurements);
+        if let Err(e) = baseline.save(&options.baseline) {
+            eprintln!("{}", e);
+            return false;
+        }
+    }
+    success && regressions == 0
+}
+
+fn run_verify(
+    day: Option<u8>,
+    answers: &Path,
+    input_dir: &Path,
+    update_readme: Option<PathBuf>,
+) -> bool {
+    let answers = match Answers::load(answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    if let Some(readme_path) = update_readme {
+        let updated = fs::read_to_string(&readme_path)
+            .map_err(|e| e.to_string())
+            .and_then(|readme| {
+                replace_readme_table(&readme, &answers.readme_table()).map_err(|e| e.to_string())
+            })
+            .and_then(|readme| fs::write(&readme_path, readme).map_err(|e| e.to_string()));
+        if let Err(e) = updated {
+            eprintln!("{}: {}", readme_path.display(), e);
+            return false;
+        }
+    }
+
+    let results = verify(&answers, input_dir, day);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for result in results.iter() {
+        println!("{}", result);
+        match result.status {
+            Status::Pass => passed += 1,
+            Status::Missing(_) => missing += 1,
+            Status::Fail { .. } | Status::Error(_) => failed += 1,
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    failed == 0
+}
+
+fn run_summary(
+    answers: &Path,
+    input_dir: &Path,
+    jobs: Option<usize>,
+    limits: Limits,
+    verbose: u8,
+) -> bool {
+    let answers = match Answers::load(answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let runner = match Runner::new(limits, verbose) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let pool = match rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+    {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let start = Instant::now();
+    let rows = pool.install(|| run_all(&all_jobs(), input_dir, &answers, &runner));
+    let wall = start.elapsed();
+    print!("{}", table(&rows, wall));
+    for row in rows.iter().filter(|row| row.outcome.is_failure()) {
+        match (&row.error, &row.expected) {
+            (Some(e), _) => eprintln!("Day {} Part {}: {}", row.day, row.part, e),
+            (None, Some(expected)) => eprintln!(
+                "Day {} Part {}: expected {} for {}",
+                row.day, row.part, expected, row.input
+            ),
+            (None, None) => {}
+        }
+    }
+    rows.iter().all(|row| !row.outcome.is_failure())
+}
+
+struct WatchOptions {
+    answers: PathBuf,
+    input_dir: PathBuf,
+    root: PathBuf,
+}
+
+fn run_watch(day: u8, part: u8, options: WatchOptions) -> bool {
+    let answers = match Answers::load(&options.answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let part = Part::try_from(part).expect("part is validated by clap");
+    let watched = Watch::new(&options.root, &options.input_dir, day, part, answers)
+        .and_then(|watch| watch.run());
+    match watched {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+// a client signed in with the session found from the current directory
+fn client(site: &SiteArgs) -> Option<Client> {
+    match Session::find(Path::new(".")) {
+        Ok(session) => Some(Client::new(&site.url, session, Cache::new(&site.cache))),
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    }
+}
+
+fn run_fetch(day: u8, input_dir: &Path, force: bool, site: &SiteArgs) -> bool {
+    let Some(client) = client(site) else {
+        return false;
+    };
+    match fetch(&client, day, input_dir, force) {
+        Ok(path) => {
+            println!("wrote {}", path.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+struct SubmitOptions {
+    answer: Option<String>,
+    answers: PathBuf,
+    input_dir: PathBuf,
+    throttle: Duration,
+    site: SiteArgs,
+}
+
+fn run_submit(day: u8, part: u8, options: SubmitOptions) -> bool {
+    let part = Part::try_from(part).expect("part is validated by clap");
+    let path = default_input_path(&options.input_dir, day, part);
+    let input_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let answer = match options.answer {
+        Some(answer) => answer,
+        None => match read_input(&path)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+            .and_then(|input| days::solve(day, part, &input).map_err(|e| e.to_string()))
+        {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("Day {} Part {}: {}", day, part, e);
+                return false;
+            }
+        },
+    };
+    let Some(client) = client(&options.site) else {
+        return false;
+    };
+    let client = client.with_throttle(options.throttle);
+    match submit(&client, day, part, &answer, &input_name, &options.answers) {
+        Ok(verdict) => {
+            println!("Day {} Part {}: {}: {}", day, part, answer, verdict);
+            verdict == Verdict::Correct
+        }
+        Err(e) => {
+            eprintln!("Day {} Part {}: {}", day, part, e);
+            false
+        }
+    }
+}
+
+fn run_new(day: u8, root: &Path) -> bool {
+    match new_day(root, day) {
+        Ok(written) => {
+            for path in written.iter() {
+                println!("wrote {}", path.display());
+            }
+            true
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logging::init(cli.verbose);
+    let success = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            input_dir,
+            format,
+            timeout,
+            memory,
+            profile,
+        } => {
+            if profile && !allocations::enable() {
+                eprintln!("the runner is not counting allocations");
+                return ExitCode::FAILURE;
+            }
+            run(
+                day,
+                part,
+                input,
+                input_dir,
+                format,
+                Limits { timeout, memory },
+                cli.verbose,
+            )
+        }
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            input_dir,
+            baseline,
+            save_baseline,
+            threshold,
+        } => run_bench(
+            day,
+            part,
+            BenchOptions {
+                iterations,
+                input_dir,
+                baseline,
+                save_baseline,
+                threshold,
+            },
+        ),
+        Command::Verify {
+            day,
+            answers,
+            input_dir,
+            update_readme,
+        } => run_verify(day, &answers, &input_dir, update_readme),
+        Command::Summary {
+            answers,
+            input_dir,
+            jobs,
+            timeout,
+            memory,
+        } => run_summary(
+            &answers,
+            &input_dir,
+            jobs,
+            Limits { timeout, memory },
+            cli.verbose,
+        ),
+        Command::Watch {
+            day,
+            part,
+            answers,
+            input_dir,
+            root,
+        } => run_watch(
+            day,
+            part,
+            WatchOptions {
+                answers,
+                input_dir,
+                root,
+            },
+        ),
+        Command::Isolated {
+            day,
+            part,
+            input_name,
+            memory,
+            profile,
+        } => {
+            if profile {
+                allocations::enable();
+            }
+            isolated_main(
+                day,
+                Part::try_from(part).expect("part is validated by clap"),
+                &input_name,
+                memory,
+            )
+        }
+        Command::Fetch {
+            day,
+            input_dir,
+            force,
+            site,
+        } => run_fetch(day, &input_dir, force, &site),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            answers,
+            input_dir,
+            throttle,
+            site,
+        } => run_submit(
+            day,
+            part,
+            SubmitOptions {
+                answer,
+                answers,
+                input_dir,
+                throttle,
+                site,
+            },
+        ),
+        Command::New { day, root } => run_new(day, &root),
+    };
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
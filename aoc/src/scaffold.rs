@@ -0,0 +1,424 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ScaffoldError {
+    #[error("{} already exists", .0.display())]
+    Exists(PathBuf),
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{}: {reason}", path.display())]
+    Unrecognized { path: PathBuf, reason: String },
+}
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+"#;
+
+const LIB_TEMPLATE: &str = r#"//! Advent of Code 2024, day {day}
+
+use aoc_core::{aoc, AocError, Part, Solution};
+
+/// Part 1, solved from the lines of the input
+pub fn solve_part1(_lines: &[String]) -> Result<u64, AocError> {
+    Err(AocError::NotImplemented {
+        day: {day},
+        part: Part::One,
+    })
+}
+
+/// Day {day}
+pub struct {name};
+
+#[aoc]
+impl Solution for {name} {
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        solve_part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::sample_test;
+
+    sample_test!(
+        day = {day},
+        part = 1,
+        file = "day{day}-part1-s1.dat",
+        expect = "",
+        ignore = "sample answer not recorded yet"
+    );
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"use std::process::ExitCode;
+
+use aoc_core::{run_main, Part};
+use {lib}::{name};
+
+fn main() -> ExitCode {
+    run_main::<{name}>(Part::{part})
+}
+"#;
+
+// names used for one day across the workspace
+struct DayNames {
+    day: u8,
+    crate_name: String,
+    lib_name: String,
+    type_name: String,
+}
+
+impl DayNames {
+    fn new(day: u8) -> Self {
+        DayNames {
+            day,
+            crate_name: format!("day-{:02}", day),
+            lib_name: format!("day_{:02}", day),
+            type_name: format!("Day{:02}", day),
+        }
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("{crate}", &self.crate_name)
+            .replace("{lib}", &self.lib_name)
+            .replace("{name}", &self.type_name)
+            .replace("{day}", &self.day.to_string())
+    }
+}
+
+// create the day-NN crate and wire it into the workspace, the runner, input/ and answers.toml
+// returns every file created or modified
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let names = DayNames::new(day);
+    let crate_dir = root.join(&names.crate_name);
+    if crate_dir.exists() {
+        return Err(ScaffoldError::Exists(crate_dir));
+    }
+
+    // check every file that gets edited before writing anything
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let days_source = root.join("aoc").join("src").join("days.rs");
+    let answers_path = root.join("answers.toml");
+    let edits = [
+        (
+            workspace_manifest.clone(),
+            add_workspace_member(&read(&workspace_manifest)?, &names.crate_name),
+        ),
+        (
+            runner_manifest.clone(),
+            add_runner_dependency(&read(&runner_manifest)?, &names),
+        ),
+        (
+            days_source.clone(),
+            register_day(&read(&days_source)?, &names),
+        ),
+    ];
+    let mut updates = Vec::new();
+    for (path, edit) in edits {
+        let text = edit.map_err(|reason| ScaffoldError::Unrecognized {
+            path: path.clone(),
+            reason,
+        })?;
+        updates.push((path, text));
+    }
+    let answers = read(&answers_path)?;
+    updates.push((answers_path, add_placeholder_answers(&answers, day)));
+
+    let mut written = Vec::new();
+    let bin_dir = crate_dir.join("src").join("bin");
+    create_dir(&bin_dir)?;
+    let crate_files = [
+        (crate_dir.join("Cargo.toml"), names.fill(MANIFEST_TEMPLATE)),
+        (
+            crate_dir.join("src").join("lib.rs"),
+            names.fill(LIB_TEMPLATE),
+        ),
+        (
+            bin_dir.join("part1.rs"),
+            names.fill(BIN_TEMPLATE).replace("{part}", "One"),
+        ),
+        (
+            bin_dir.join("part2.rs"),
+            names.fill(BIN_TEMPLATE).replace("{part}", "Two"),
+        ),
+    ];
+    for (path, text) in crate_files {
+        write(&path, &text)?;
+        written.push(path);
+    }
+
+    let input_dir = root.join("input");
+    create_dir(&input_dir)?;
+    for file_name in input_file_names(day) {
+        let path = input_dir.join(file_name);
+        if !path.exists() {
+            write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    for (path, text) in updates {
+        write(&path, &text)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+// the sample and puzzle input shared by both parts
+fn input_file_names(day: u8) -> [String; 2] {
+    [
+        format!("day{}-part1-s1.dat", day),
+        format!("day{}-part1.dat", day),
+    ]
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, text: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, text).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn create_dir(path: &Path) -> Result<(), ScaffoldError> {
+    fs::create_dir_all(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// add the crate to the single line `members = [ ... ]` list, kept sorted
+fn add_workspace_member(manifest: &str, crate_name: &str) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("no workspace members list")?
+        + "members = [".len();
+    let end = manifest[start..]
+        .find(']')
+        .ok_or("unterminated workspace members list")?
+        + start;
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+    if members.contains(&crate_name) {
+        return Err(format!("{} is already a workspace member", crate_name));
+    }
+    members.push(crate_name);
+    members.sort();
+    let line: Vec<String> = members.iter().map(|m| format!("\"{}\",", m)).collect();
+    Ok(format!(
+        "{}\n    {}\n{}",
+        &manifest[..start],
+        line.join(" "),
+        &manifest[end..]
+    ))
+}
+
+// insert a line among the consecutive lines sharing its prefix, keeping them sorted
+fn insert_sorted_line(text: &str, prefix: &str, new_line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let first = lines
+        .iter()
+        .position(|line| line.starts_with(prefix))
+        .ok_or(format!("no line starting with `{}`", prefix))?;
+    let mut index = first;
+    while index < lines.len() && lines[index].starts_with(prefix) {
+        if lines[index] == new_line {
+            return Err(format!("`{}` is already present", new_line));
+        }
+        if lines[index] > new_line {
+            break;
+        }
+        index += 1;
+    }
+    lines.insert(index, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_runner_dependency(manifest: &str, names: &DayNames) -> Result<String, String> {
+    let dependency = format!(
+        "{} = {{ path = \"../{}\" }}",
+        names.crate_name, names.crate_name
+    );
+    insert_sorted_line(manifest, "day-", &dependency)
+}
+
+// link the crate into the runner so its #[aoc] Solution gets registered
+fn register_day(source: &str, names: &DayNames) -> Result<String, String> {
+    let link = format!("use {} as _;", names.lib_name);
+    insert_sorted_line(source, "use day_", &link)
+}
+
+// records for both parts against the sample and the puzzle input, answers left out
+fn add_placeholder_answers(answers: &str, day: u8) -> String {
+    let mut text = answers.trim_end().to_string();
+    for part in 1..=2 {
+        for input in input_file_names(day) {
+            text.push_str(&format!(
+                "\n\n[[answer]]\nday = {}\npart = {}\ninput = \"{}\"",
+                day, part, input
+            ));
+        }
+    }
+    text.push('\n');
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+
+    // trimmed stand-ins for the files new_day edits, fixed so the tests do not follow the
+    // workspace as days are added
+    const WORKSPACE_MANIFEST: &str = r#"[workspace]
+resolver = "2"
+
+members = [
+    "aoc", "aoc-core", "day-01", "day-02",
+]
+"#;
+    const RUNNER_MANIFEST: &str = r#"[package]
+name = "aoc"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+day-01 = { path = "../day-01" }
+day-02 = { path = "../day-02" }
+"#;
+    const DAYS_SOURCE: &str = r#"use aoc_core::registry;
+
+// link the day crates, whose #[aoc] Solutions register themselves with the runner
+use day_01 as _;
+use day_02 as _;
+
+pub fn days() -> Vec<u8> {
+    registry::days()
+}
+"#;
+
+    // a copy of the files new_day edits in a scratch directory
+    fn scratch_workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("aoc").join("src")).unwrap();
+        fs::write(root.path().join("Cargo.toml"), WORKSPACE_MANIFEST).unwrap();
+        fs::write(root.path().join("aoc").join("Cargo.toml"), RUNNER_MANIFEST).unwrap();
+        fs::write(root.path().join("aoc/src/days.rs"), DAYS_SOURCE).unwrap();
+        fs::write(
+            root.path().join("answers.toml"),
+            "[[answer]]\nday = 1\npart = 1\ninput = \"day1-part1.dat\"\nanswer = \"11\"\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\", \"day-01\", \"day-03\",\n]\n";
+        assert_eq!(
+            add_workspace_member(manifest, "day-02").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\", \"day-01\", \"day-02\", \"day-03\",\n]\n"
+        );
+        assert!(add_workspace_member(manifest, "day-01").is_err());
+        assert!(add_workspace_member("[workspace]\n", "day-02").is_err());
+    }
+
+    #[test]
+    fn test_insert_sorted_line() {
+        let text = "a\nday-01\nday-03\nb\n";
+        assert_eq!(
+            insert_sorted_line(text, "day-", "day-02").unwrap(),
+            "a\nday-01\nday-02\nday-03\nb\n"
+        );
+        assert_eq!(
+            insert_sorted_line(text, "day-", "day-04").unwrap(),
+            "a\nday-01\nday-03\nday-04\nb\n"
+        );
+        assert!(insert_sorted_line(text, "day-", "day-03").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let source =
+            "use aoc_core::registry;\n\nuse day_01 as _;\nuse day_03 as _;\n\npub fn days() {}\n";
+        let source = register_day(source, &DayNames::new(2)).unwrap();
+        assert_eq!(
+            source,
+            "use aoc_core::registry;\n\nuse day_01 as _;\nuse day_02 as _;\nuse day_03 as _;\n\npub fn days() {}\n"
+        );
+        let source = register_day(&source, &DayNames::new(25)).unwrap();
+        assert!(source.contains("use day_03 as _;\nuse day_25 as _;\n"));
+        assert!(register_day(&source, &DayNames::new(25)).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = scratch_workspace();
+        let written = new_day(root.path(), 25).unwrap();
+        assert!(written.contains(&root.path().join("day-25/src/bin/part2.rs")));
+        assert!(root.path().join("input/day25-part1-s1.dat").exists());
+
+        let lib = fs::read_to_string(root.path().join("day-25/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day25;"));
+        assert!(lib.contains("const DAY: u8 = 25;"));
+        assert!(lib.contains("#[aoc]\nimpl Solution for Day25 {"));
+        assert!(lib.contains("file = \"day25-part1-s1.dat\","));
+
+        let manifest = fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("\"day-02\", \"day-25\","));
+        let runner = fs::read_to_string(root.path().join("aoc/Cargo.toml")).unwrap();
+        assert!(runner
+            .ends_with("day-02 = { path = \"../day-02\" }\nday-25 = { path = \"../day-25\" }\n"));
+        let days = fs::read_to_string(root.path().join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains("use day_02 as _;\nuse day_25 as _;\n"));
+
+        let answers = Answers::load(&root.path().join("answers.toml")).unwrap();
+        let placeholders: Vec<_> = answers.records.iter().filter(|r| r.day == 25).collect();
+        assert_eq!(placeholders.len(), 4);
+        assert!(placeholders.iter().all(|r| r.answer.is_none()));
+        assert_eq!(
+            answers.expected(1, aoc_core::Part::One, "day1-part1.dat"),
+            Some("11")
+        );
+    }
+
+    #[test]
+    fn test_new_day_exists() {
+        let root = scratch_workspace();
+        new_day(root.path(), 25).unwrap();
+        assert!(matches!(
+            new_day(root.path(), 25),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+}
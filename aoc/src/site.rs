@@ -0,0 +1,178 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use aoc_client::{Client, ClientError, Verdict};
+use aoc_core::Part;
+use aoc_crypt::{encrypted_path, input_exists, CryptError, Key};
+
+use crate::answers::{Answers, AnswersError};
+
+#[derive(Debug, Error)]
+pub enum SiteError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    Crypt(#[from] CryptError),
+    #[error(transparent)]
+    Answers(#[from] AnswersError),
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{}: already exists, pass --force to replace it", .0.display())]
+    Exists(PathBuf),
+}
+
+// download the day's input into input_dir as dayN-part1.dat, encrypted when a key is found;
+// the path written. A forced fetch removes the copy in the other format, which would otherwise
+// shadow the new input or leave it in clear text
+pub fn fetch(
+    client: &Client,
+    day: u8,
+    input_dir: &Path,
+    force: bool,
+) -> Result<PathBuf, SiteError> {
+    let key = match Key::find(input_dir) {
+        Ok(key) => Some(key),
+        Err(CryptError::MissingKey) => None,
+        Err(e) => return Err(e.into()),
+    };
+    fetch_with(client, day, input_dir, force, key)
+}
+
+fn fetch_with(
+    client: &Client,
+    day: u8,
+    input_dir: &Path,
+    force: bool,
+    key: Option<Key>,
+) -> Result<PathBuf, SiteError> {
+    let clear = input_dir.join(format!("day{}-part1.dat", day));
+    if input_exists(&clear) && !force {
+        return Err(SiteError::Exists(clear));
+    }
+    let input = client.input(day)?;
+    let encrypted = encrypted_path(&clear);
+    let (path, stale, contents) = match key {
+        Some(key) => (encrypted, clear, key.encrypt(input.as_bytes())),
+        None => (clear, encrypted, input.into_bytes()),
+    };
+    fs::write(&path, contents).map_err(|source| SiteError::Io {
+        path: path.clone(),
+        source,
+    })?;
+    match fs::remove_file(&stale) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(SiteError::Io {
+            path: stale,
+            source: e,
+        }),
+        _ => Ok(path),
+    }
+}
+
+// submit an answer for the input named input_name, recording it in the registry at
+// answers_path once the site accepts it
+pub fn submit(
+    client: &Client,
+    day: u8,
+    part: Part,
+    answer: &str,
+    input_name: &str,
+    answers_path: &Path,
+) -> Result<Verdict, SiteError> {
+    let verdict = client.submit(day, part, answer)?;
+    if verdict == Verdict::Correct {
+        let mut answers = Answers::load(answers_path)?;
+        if answers.expected(day, part, input_name) != Some(answer) {
+            answers.record(day, part, input_name, answer);
+            answers.save(answers_path)?;
+        }
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_client::mock::MockServer;
+    use aoc_client::{Cache, Session};
+
+    use super::*;
+    use crate::input::read_input;
+
+    const SESSION: &str = "cafe";
+
+    fn client(server: &MockServer, dir: &Path) -> Client {
+        Client::new(
+            &server.url(),
+            Session::new(SESSION),
+            Cache::new(&dir.join(".aoc-cache")),
+        )
+        .with_throttle(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let server = MockServer::start(SESSION);
+        server.add_day(9, "2333133121414131402\n", ["1928", "2858"]);
+        let dir = tempfile::tempdir().unwrap();
+        let input_dir = dir.path().join("input");
+        fs::create_dir(&input_dir).unwrap();
+        let client = client(&server, dir.path());
+
+        let path = fetch_with(&client, 9, &input_dir, false, None).unwrap();
+        assert_eq!(path, input_dir.join("day9-part1.dat"));
+        assert_eq!(read_input(&path).unwrap(), "2333133121414131402\n");
+        assert!(matches!(
+            fetch(&client, 9, &input_dir, false),
+            Err(SiteError::Exists(_))
+        ));
+
+        // with a key the input is stored encrypted, replacing the clear text copy, and the cache
+        // spares a second download
+        let key = Key::generate();
+        key.save(&dir.path().join(".aoc-key")).unwrap();
+        let encrypted = fetch_with(&client, 9, &input_dir, true, Some(key)).unwrap();
+        assert_eq!(encrypted, input_dir.join("day9-part1.dat.enc"));
+        assert!(!path.exists());
+        assert_eq!(read_input(&path).unwrap(), "2333133121414131402\n");
+        assert_eq!(server.requests(), vec!["GET /2024/day/9/input"]);
+
+        // and without the key again, the clear text copy replaces the encrypted one
+        assert_eq!(
+            fetch_with(&client, 9, &input_dir, true, None).unwrap(),
+            path
+        );
+        assert!(!encrypted.exists());
+        assert_eq!(read_input(&path).unwrap(), "2333133121414131402\n");
+    }
+
+    #[test]
+    fn test_submit_records_accepted_answers() {
+        let server = MockServer::start(SESSION);
+        server.add_day(9, "12345\n", ["60", "132"]);
+        let dir = tempfile::tempdir().unwrap();
+        let answers_path = dir.path().join("answers.toml");
+        Answers::default().save(&answers_path).unwrap();
+        let client = client(&server, dir.path());
+        let submit = |answer| {
+            submit(
+                &client,
+                9,
+                Part::One,
+                answer,
+                "day9-part1.dat",
+                &answers_path,
+            )
+        };
+
+        assert!(matches!(submit("61").unwrap(), Verdict::Incorrect { .. }));
+        assert_eq!(Answers::load(&answers_path).unwrap(), Answers::default());
+        assert_eq!(submit("60").unwrap(), Verdict::Correct);
+        let answers = Answers::load(&answers_path).unwrap();
+        assert_eq!(answers.expected(9, Part::One, "day9-part1.dat"), Some("60"));
+        assert_eq!(answers.records.len(), 1);
+    }
+}
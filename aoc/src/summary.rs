@@ -0,0 +1,343 @@
+use std::fmt::{self, Display, Formatter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use aoc_core::{Part, ReportStatus};
+
+use crate::answers::Answers;
+use crate::days;
+use crate::input::{default_input_path, read_input};
+use crate::limits::Runner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Unchecked,
+    NotImplemented,
+    Error,
+    TimedOut,
+    ExceededMemory,
+}
+
+impl Outcome {
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Outcome::Fail | Outcome::Error | Outcome::TimedOut | Outcome::ExceededMemory
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail => write!(f, "FAIL"),
+            Outcome::Unchecked => write!(f, "unchecked"),
+            Outcome::NotImplemented => write!(f, "not implemented"),
+            Outcome::Error => write!(f, "ERROR"),
+            Outcome::TimedOut => write!(f, "timed out"),
+            Outcome::ExceededMemory => write!(f, "exceeded memory"),
+        }
+    }
+}
+
+// one line of the summary table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub outcome: Outcome,
+    pub error: Option<String>,
+    pub elapsed: Duration,
+}
+
+// every registered day and part, in order
+pub fn all_jobs() -> Vec<(u8, Part)> {
+    days::days()
+        .into_iter()
+        .flat_map(|day| Part::ALL.into_iter().map(move |part| (day, part)))
+        .collect()
+}
+
+// read the default input, solve it with the runner and compare against the recorded answer
+pub fn run_job(day: u8, part: Part, input_dir: &Path, answers: &Answers, runner: &Runner) -> Row {
+    let start = Instant::now();
+    let path = default_input_path(input_dir, day, part);
+    let input_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let expected = answers.expected(day, part, &input_name).map(String::from);
+    let mut row = Row {
+        day,
+        part,
+        input: input_name,
+        answer: None,
+        expected,
+        outcome: Outcome::Error,
+        error: None,
+        elapsed: Duration::ZERO,
+    };
+
+    match read_input(&path) {
+        Ok(input) => {
+            let report = runner.report(day, part, &input, &path.display().to_string());
+            row.outcome = match (report.status, &report.answer, &row.expected) {
+                (ReportStatus::Solved, Some(answer), Some(expected)) if answer == expected => {
+                    Outcome::Pass
+                }
+                (ReportStatus::Solved, _, Some(_)) => Outcome::Fail,
+                (ReportStatus::Solved, _, None) => Outcome::Unchecked,
+                (ReportStatus::NotImplemented, _, _) => Outcome::NotImplemented,
+                (ReportStatus::Failed, _, _) => Outcome::Error,
+                (ReportStatus::TimedOut, _, _) => Outcome::TimedOut,
+                (ReportStatus::ExceededMemory, _, _) => Outcome::ExceededMemory,
+            };
+            row.answer = report.answer;
+            row.error = report.error;
+        }
+        Err(e) => row.error = Some(format!("{}: {}", path.display(), e)),
+    }
+    row.elapsed = start.elapsed();
+    row
+}
+
+// run the jobs concurrently on the current rayon pool, rows come back in job order
+pub fn run_all(
+    jobs: &[(u8, Part)],
+    input_dir: &Path,
+    answers: &Answers,
+    runner: &Runner,
+) -> Vec<Row> {
+    jobs.par_iter()
+        .map(|&(day, part)| run_job(day, part, input_dir, answers, runner))
+        .collect()
+}
+
+// aligned table of the rows followed by the totals; wall is the time the whole run took
+pub fn table(rows: &[Row], wall: Duration) -> String {
+    let header = ["Day", "Part", "Answer", "Expected", "Status", "Time"].map(String::from);
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.day.to_string(),
+                row.part.to_string(),
+                row.answer.clone().unwrap_or_else(|| "-".to_string()),
+                row.expected.clone().unwrap_or_else(|| "-".to_string()),
+                row.outcome.to_string(),
+                format!("{:.2?}", row.elapsed),
+            ]
+        })
+        .collect();
+    let mut widths = header.clone().map(|h| h.len());
+    for row in cells.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            // durations may print a µ, pad by characters rather than bytes
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let write_row = |table: &mut String, row: &[String; 6]| {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .enumerate()
+            .map(|(column, (cell, &width))| match column {
+                // numbers and times read better right aligned
+                0 | 1 | 5 => format!("{:>width$}", cell, width = width),
+                _ => format!("{:<width$}", cell, width = width),
+            })
+            .collect();
+        writeln!(table, "{}", line.join("  ").trim_end()).unwrap();
+    };
+    write_row(&mut table, &header);
+    let rule = widths.map(|width| "-".repeat(width));
+    write_row(&mut table, &rule);
+    for row in cells.iter() {
+        write_row(&mut table, row);
+    }
+
+    let count = |outcome| rows.iter().filter(|row| row.outcome == outcome).count();
+    let total: Duration = rows.iter().map(|row| row.elapsed).sum();
+    writeln!(
+        table,
+        "{} passed, {} failed, {} unchecked, {} not implemented, {} errors",
+        count(Outcome::Pass),
+        count(Outcome::Fail),
+        count(Outcome::Unchecked),
+        count(Outcome::NotImplemented),
+        count(Outcome::Error),
+    )
+    .unwrap();
+    let stopped = count(Outcome::TimedOut) + count(Outcome::ExceededMemory);
+    if stopped > 0 {
+        writeln!(
+            table,
+            "{} timed out, {} exceeded memory",
+            count(Outcome::TimedOut),
+            count(Outcome::ExceededMemory),
+        )
+        .unwrap();
+    }
+    writeln!(
+        table,
+        "total {:.2?} across solutions, {:.2?} wall clock",
+        total, wall
+    )
+    .unwrap();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::answers::AnswerRecord;
+
+    fn input_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../input")
+    }
+
+    fn answers(records: &[(u8, u8, &str)]) -> Answers {
+        Answers {
+            records: records
+                .iter()
+                .map(|&(day, part, answer)| AnswerRecord {
+                    day,
+                    part,
+                    input: format!("day{}-part1.dat", day),
+                    answer: Some(answer.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    fn row(day: u8, part: Part, outcome: Outcome) -> Row {
+        Row {
+            day,
+            part,
+            input: format!("day{}-part1.dat", day),
+            answer: Some("42".to_string()),
+            expected: Some("42".to_string()),
+            outcome,
+            error: None,
+            elapsed: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn test_all_jobs() {
+        let jobs = all_jobs();
+        assert_eq!(jobs.len(), days::days().len() * 2);
+        assert_eq!(jobs[0], (1, Part::One));
+        assert_eq!(jobs[1], (1, Part::Two));
+    }
+
+    #[test]
+    fn test_run_job_outcomes() {
+        // the samples stand in for the real inputs, which need the key
+        let dir = tempfile::tempdir().unwrap();
+        for day in [1, 2, 6] {
+            fs::copy(
+                input_dir().join(format!("day{}-part1-s1.dat", day)),
+                dir.path().join(format!("day{}-part1.dat", day)),
+            )
+            .unwrap();
+        }
+        let answers = answers(&[(1, 1, "11"), (2, 2, "1")]);
+        let pass = run_job(1, Part::One, dir.path(), &answers, &Runner::InProcess);
+        assert_eq!(pass.outcome, Outcome::Pass);
+        assert_eq!(pass.input, "day1-part1.dat");
+        let fail = run_job(2, Part::Two, dir.path(), &answers, &Runner::InProcess);
+        assert_eq!(fail.outcome, Outcome::Fail);
+        assert_eq!(fail.answer.as_deref(), Some("4"));
+        let unchecked = run_job(2, Part::One, dir.path(), &answers, &Runner::InProcess);
+        assert_eq!(unchecked.outcome, Outcome::Unchecked);
+        assert_eq!(unchecked.expected, None);
+        let not_implemented = run_job(6, Part::Two, dir.path(), &answers, &Runner::InProcess);
+        assert_eq!(not_implemented.outcome, Outcome::NotImplemented);
+        assert!(!not_implemented.outcome.is_failure());
+    }
+
+    #[test]
+    fn test_run_job_missing_input() {
+        let row = run_job(
+            1,
+            Part::One,
+            Path::new("no-such-dir"),
+            &Answers::default(),
+            &Runner::InProcess,
+        );
+        assert_eq!(row.outcome, Outcome::Error);
+        assert!(row.error.unwrap().starts_with("no-such-dir"));
+    }
+
+    #[test]
+    fn test_run_all_keeps_job_order() {
+        let jobs = all_jobs();
+        let rows = run_all(&jobs, &input_dir(), &Answers::default(), &Runner::InProcess);
+        let order: Vec<(u8, Part)> = rows.iter().map(|row| (row.day, row.part)).collect();
+        assert_eq!(order, jobs);
+    }
+
+    #[test]
+    fn test_table() {
+        let mut unsolved = row(6, Part::Two, Outcome::NotImplemented);
+        unsolved.answer = None;
+        unsolved.expected = None;
+        let rows = [row(1, Part::One, Outcome::Pass), unsolved];
+        let table = table(&rows, Duration::from_millis(4));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Day  Part  Answer  Expected  Status             Time"
+        );
+        assert_eq!(
+            lines[1],
+            "---  ----  ------  --------  ---------------  ------"
+        );
+        assert_eq!(
+            lines[2],
+            "  1     1  42      42        pass             3.00ms"
+        );
+        assert_eq!(
+            lines[3],
+            "  6     2  -       -         not implemented  3.00ms"
+        );
+        assert_eq!(
+            lines[4],
+            "1 passed, 0 failed, 0 unchecked, 1 not implemented, 0 errors"
+        );
+        assert_eq!(lines[5], "total 6.00ms across solutions, 4.00ms wall clock");
+    }
+
+    #[test]
+    fn test_table_stopped_solutions() {
+        let mut slow = row(6, Part::One, Outcome::TimedOut);
+        slow.answer = None;
+        let rows = [slow, row(5, Part::Two, Outcome::ExceededMemory)];
+        assert!(rows.iter().all(|row| row.outcome.is_failure()));
+        let table = table(&rows, Duration::from_millis(4));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[2],
+            "  6     1  -       42        timed out        3.00ms"
+        );
+        assert_eq!(
+            lines[4],
+            "0 passed, 0 failed, 0 unchecked, 0 not implemented, 0 errors"
+        );
+        assert_eq!(lines[5], "1 timed out, 1 exceeded memory");
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+
+use aoc_core::AocError;
+
+use crate::answers::{AnswerRecord, Answers};
+use crate::days;
+use crate::input::{input_exists, read_input};
+
+#[derive(Debug)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Error(AocError),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "fail (expected {}, got {})", expected, actual)
+            }
+            Status::Missing(reason) => write!(f, "missing ({})", reason),
+            Status::Error(e) => write!(f, "error ({})", e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub record: AnswerRecord,
+    pub status: Status,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} Part {} {}: {}",
+            self.record.day, self.record.part, self.record.input, self.status
+        )
+    }
+}
+
+// run the solution for a recorded answer against its input file
+pub fn verify_record(record: &AnswerRecord, input_dir: &Path) -> Status {
+    let Some(expected) = record.answer.clone() else {
+        return Status::Missing("no answer recorded".to_string());
+    };
+    let path = input_dir.join(&record.input);
+    if !input_exists(&path) {
+        return Status::Missing(format!("{} not found", path.display()));
+    }
+    let input = match read_input(&path) {
+        Ok(input) => input,
+        Err(e) => return Status::Error(AocError::from(e)),
+    };
+    match days::solve(record.day, record.part(), &input) {
+        Ok(actual) if actual == expected => Status::Pass,
+        Ok(actual) => Status::Fail { expected, actual },
+        Err(e) => Status::Error(e),
+    }
+}
+
+pub fn verify(answers: &Answers, input_dir: &Path, day: Option<u8>) -> Vec<Verification> {
+    answers
+        .records
+        .iter()
+        .filter(|record| day.is_none_or(|day| record.day == day))
+        .map(|record| Verification {
+            record: record.clone(),
+            status: verify_record(record, input_dir),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn input_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../input")
+    }
+
+    fn record(day: u8, part: u8, input: &str, answer: Option<&str>) -> AnswerRecord {
+        AnswerRecord {
+            day,
+            part,
+            input: input.to_string(),
+            answer: answer.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_verify_pass() {
+        let status = verify_record(&record(1, 1, "day1-part1-s1.dat", Some("11")), &input_dir());
+        assert!(matches!(status, Status::Pass));
+    }
+
+    #[test]
+    fn test_verify_fail() {
+        let status = verify_record(&record(1, 2, "day1-part1-s1.dat", Some("11")), &input_dir());
+        assert!(status.is_failure());
+        assert_eq!(status.to_string(), "fail (expected 11, got 31)");
+    }
+
+    #[test]
+    fn test_verify_missing() {
+        let status = verify_record(&record(1, 1, "day1-part1-s1.dat", None), &input_dir());
+        assert!(matches!(status, Status::Missing(_)));
+        let status = verify_record(&record(9, 1, "day9-part1.dat", Some("1")), &input_dir());
+        assert!(matches!(status, Status::Missing(_)));
+        assert!(!status.is_failure());
+    }
+
+    #[test]
+    fn test_verify_error() {
+        let status = verify_record(&record(6, 2, "day6-part1-s1.dat", Some("6")), &input_dir());
+        assert!(matches!(
+            status,
+            Status::Error(AocError::NotImplemented { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_filters_day() {
+        let answers = Answers {
+            records: vec![
+                record(1, 1, "day1-part1-s1.dat", Some("11")),
+                record(2, 1, "day2-part1-s1.dat", Some("2")),
+            ],
+        };
+        let results = verify(&answers, &input_dir(), Some(2));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].record.day, 2);
+        assert_eq!(
+            results[0].to_string(),
+            "Day 2 Part 1 day2-part1-s1.dat: pass"
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use thiserror::Error;
+
+use aoc_core::Part;
+
+use crate::answers::Answers;
+use crate::input::{default_input_path, default_sample_path, input_exists};
+
+// quiet period after a change before rebuilding, so one save touching several files runs once
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Error)]
+pub enum WatchError {
+    #[error("watching for changes failed: {0}")]
+    Notify(#[from] notify::Error),
+    #[error("{path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+}
+
+// what the part binary printed for one input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+}
+
+impl Outcome {
+    // read the report the day binaries print with --format json, else the error cargo printed
+    pub fn from_output(stdout: &str, stderr: &str) -> Self {
+        let report: Option<serde_json::Value> = serde_json::from_str(stdout.trim()).ok();
+        let field = |name| {
+            report
+                .as_ref()
+                .and_then(|report| report[name].as_str())
+                .map(str::to_string)
+        };
+        match (field("answer"), field("error")) {
+            (Some(answer), _) => Outcome::Answer(answer),
+            (None, Some(e)) => Outcome::Error(e),
+            (None, None) => Outcome::Error(
+                stderr
+                    .lines()
+                    .rfind(|line| !line.trim().is_empty())
+                    .unwrap_or("no report printed")
+                    .trim()
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+// one line about a run: the answer, then how it compares to the expected answer and the last run
+pub fn describe(outcome: &Outcome, previous: Option<&Outcome>, expected: Option<&str>) -> String {
+    let mut notes = Vec::new();
+    let text = match outcome {
+        Outcome::Answer(answer) => {
+            match expected {
+                Some(expected) if expected == answer => notes.push("pass".to_string()),
+                Some(expected) => notes.push(format!("FAIL, expected {}", expected)),
+                None => {}
+            }
+            answer.clone()
+        }
+        Outcome::Error(e) => format!("error: {}", e),
+    };
+    notes.push(match previous {
+        None => "first run".to_string(),
+        Some(previous) if previous == outcome => "unchanged".to_string(),
+        Some(Outcome::Answer(answer)) => format!("was {}", answer),
+        Some(Outcome::Error(_)) => "was an error".to_string(),
+    });
+    format!("{} ({})", text, notes.join("; "))
+}
+
+// an input file the part is rerun against
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Watched {
+    label: &'static str,
+    path: PathBuf,
+    file_name: String,
+}
+
+// reruns one day and part whenever its crate or inputs change
+pub struct Watch {
+    day: u8,
+    part: Part,
+    root: PathBuf,
+    crate_dir: PathBuf,
+    input_dir: PathBuf,
+    answers: Answers,
+    inputs: Vec<Watched>,
+    last: HashMap<PathBuf, Outcome>,
+}
+
+fn absolute(path: &Path) -> Result<PathBuf, WatchError> {
+    fs::canonicalize(path).map_err(|source| WatchError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+impl Watch {
+    pub fn new(
+        root: &Path,
+        input_dir: &Path,
+        day: u8,
+        part: Part,
+        answers: Answers,
+    ) -> Result<Self, WatchError> {
+        let root = absolute(root)?;
+        let crate_dir = absolute(&root.join(format!("day-{:02}", day)))?;
+        let input_dir = absolute(input_dir)?;
+        let inputs = [
+            ("sample", default_sample_path(&input_dir, day, part)),
+            ("input", default_input_path(&input_dir, day, part)),
+        ]
+        .into_iter()
+        .map(|(label, path)| Watched {
+            label,
+            file_name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path,
+        })
+        .collect();
+        Ok(Self {
+            day,
+            part,
+            root,
+            crate_dir,
+            input_dir,
+            answers,
+            inputs,
+            last: HashMap::new(),
+        })
+    }
+
+    // sources of the day crate, skipping editor swap and backup files, and the watched inputs
+    fn is_relevant(&self, path: &Path) -> bool {
+        let Some(file_name) = path.file_name().map(|name| name.to_string_lossy()) else {
+            return false;
+        };
+        if path.starts_with(&self.crate_dir) {
+            return !file_name.starts_with('.') && !file_name.ends_with('~');
+        }
+        let plain = file_name
+            .strip_suffix(&format!(".{}", aoc_crypt::EXTENSION))
+            .unwrap_or(&file_name);
+        path.parent() == Some(self.input_dir.as_path())
+            && self.inputs.iter().any(|input| input.file_name == plain)
+    }
+
+    // the relevant files a filesystem event touched
+    fn changed(&self, event: Event) -> Vec<PathBuf> {
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            return Vec::new();
+        }
+        event
+            .paths
+            .into_iter()
+            .filter(|path| self.is_relevant(path))
+            .collect()
+    }
+
+    fn cargo(&self, args: &[&str], extra: &[&Path]) -> io::Result<Output> {
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+        Command::new(cargo)
+            .args(args)
+            .args(extra)
+            .current_dir(&self.root)
+            .output()
+    }
+
+    // rebuild the part binary, then run it on each input; the lines to print
+    pub fn rerun(&mut self) -> Vec<String> {
+        let package = format!("day-{:02}", self.day);
+        let bin = format!("part{}", self.part);
+        let build = self.cargo(
+            &["build", "--quiet", "--package", &package, "--bin", &bin],
+            &[],
+        );
+        match build {
+            Ok(output) if output.status.success() => {}
+            Ok(output) => {
+                return vec![
+                    String::from_utf8_lossy(&output.stderr)
+                        .trim_end()
+                        .to_string(),
+                    "build failed".to_string(),
+                ]
+            }
+            Err(e) => return vec![format!("cargo: {}", e)],
+        }
+
+        let mut lines = Vec::new();
+        for input in self.inputs.iter() {
+            if !input_exists(&input.path) {
+                lines.push(format!("{} {}: missing", input.label, input.file_name));
+                continue;
+            }
+            let run = self.cargo(
+                &[
+                    "run",
+                    "--quiet",
+                    "--package",
+                    &package,
+                    "--bin",
+                    &bin,
+                    "--",
+                    "--format",
+                    "json",
+                ],
+                &[&input.path],
+            );
+            let outcome = match run {
+                Ok(output) => Outcome::from_output(
+                    &String::from_utf8_lossy(&output.stdout),
+                    &String::from_utf8_lossy(&output.stderr),
+                ),
+                Err(e) => Outcome::Error(format!("cargo: {}", e)),
+            };
+            let expected = self.answers.expected(self.day, self.part, &input.file_name);
+            lines.push(format!(
+                "{} {}: {}",
+                input.label,
+                input.file_name,
+                describe(&outcome, self.last.get(&input.path), expected)
+            ));
+            self.last.insert(input.path.clone(), outcome);
+        }
+        lines
+    }
+
+    // run once, then again after every burst of changes until interrupted
+    pub fn run(mut self) -> Result<(), WatchError> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&self.crate_dir, RecursiveMode::Recursive)?;
+        watcher.watch(&self.input_dir, RecursiveMode::NonRecursive)?;
+        println!(
+            "watching {} and {} for day {} part {}",
+            self.crate_dir.display(),
+            self.input_dir.display(),
+            self.day,
+            self.part
+        );
+        for line in self.rerun() {
+            println!("{}", line);
+        }
+        while let Ok(event) = events.recv() {
+            let mut changed = self.changed(event?);
+            if changed.is_empty() {
+                continue;
+            }
+            while let Ok(event) = events.recv_timeout(DEBOUNCE) {
+                changed.extend(self.changed(event?));
+            }
+            changed.sort();
+            changed.dedup();
+            let names: Vec<String> = changed
+                .iter()
+                .map(|path| {
+                    let path = path.strip_prefix(&self.root).unwrap_or(path);
+                    path.display().to_string()
+                })
+                .collect();
+            println!("--- changed: {}", names.join(", "));
+            for line in self.rerun() {
+                println!("{}", line);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notify::event::{AccessKind, ModifyKind};
+
+    use super::*;
+
+    fn workspace() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+    }
+
+    fn watch(day: u8, part: Part) -> Watch {
+        let root = workspace();
+        Watch::new(&root, &root.join("input"), day, part, Answers::default()).unwrap()
+    }
+
+    #[test]
+    fn test_outcome_from_output() {
+        let report = r#"{"day":4,"part":2,"answer":"9","error":null}"#;
+        assert_eq!(
+            Outcome::from_output(report, ""),
+            Outcome::Answer("9".to_string())
+        );
+        let report = r#"{"day":4,"part":2,"answer":null,"error":"invalid input: empty"}"#;
+        assert_eq!(
+            Outcome::from_output(report, ""),
+            Outcome::Error("invalid input: empty".to_string())
+        );
+        assert_eq!(
+            Outcome::from_output("", "error: file not found\n\n"),
+            Outcome::Error("error: file not found".to_string())
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let answer = |text: &str| Outcome::Answer(text.to_string());
+        assert_eq!(
+            describe(&answer("9"), None, Some("9")),
+            "9 (pass; first run)"
+        );
+        assert_eq!(
+            describe(&answer("8"), Some(&answer("9")), Some("9")),
+            "8 (FAIL, expected 9; was 9)"
+        );
+        assert_eq!(
+            describe(&answer("1873"), Some(&answer("1873")), None),
+            "1873 (unchanged)"
+        );
+        let error = Outcome::Error("boom".to_string());
+        assert_eq!(
+            describe(&error, Some(&answer("3")), Some("3")),
+            "error: boom (was 3)"
+        );
+        assert_eq!(
+            describe(&answer("3"), Some(&error), None),
+            "3 (was an error)"
+        );
+    }
+
+    #[test]
+    fn test_watched_inputs() {
+        let watch = watch(3, Part::Two);
+        let names: Vec<&str> = watch
+            .inputs
+            .iter()
+            .map(|input| input.file_name.as_str())
+            .collect();
+        assert_eq!(names, ["day3-part2-s1.dat", "day3-part1.dat"]);
+    }
+
+    #[test]
+    fn test_is_relevant() {
+        let watch = watch(4, Part::Two);
+        let source = watch.crate_dir.join("src").join("part2.rs");
+        assert!(watch.is_relevant(&source));
+        assert!(!watch.is_relevant(&watch.crate_dir.join("src").join(".part2.rs.swp")));
+        assert!(watch.is_relevant(&watch.input_dir.join("day4-part1-s1.dat")));
+        assert!(watch.is_relevant(&watch.input_dir.join("day4-part1.dat.enc")));
+        assert!(!watch.is_relevant(&watch.input_dir.join("day5-part1.dat")));
+        assert!(!watch.is_relevant(&watch.root.join("day-05").join("src").join("lib.rs")));
+
+        let event = |kind| Event::new(kind).add_path(source.clone());
+        assert_eq!(
+            watch.changed(event(EventKind::Modify(ModifyKind::Any))),
+            vec![source.clone()]
+        );
+        assert!(watch
+            .changed(event(EventKind::Access(AccessKind::Any)))
+            .is_empty());
+    }
+}
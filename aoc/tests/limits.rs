@@ -0,0 +1,84 @@
+// Runs solutions in the runner's isolated child process and checks the limits stop them
+use std::fmt::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc::limits::{run_isolated, Limits};
+use aoc_core::{Part, ReportStatus};
+
+fn program() -> &'static Path {
+    Path::new(env!("CARGO_BIN_EXE_aoc"))
+}
+
+// the guard turns right at each wall and walks this loop forever
+const CYCLING_MAP: &str = ".#..\n...#\n#^..\n..#.\n";
+
+#[test]
+fn solves_within_the_limits() {
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(60)),
+        memory: Some(1 << 30),
+    };
+    let report = run_isolated(
+        program(),
+        limits,
+        0,
+        1,
+        Part::One,
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+        "sample.dat",
+    );
+    assert_eq!(report.status, ReportStatus::Solved, "{:?}", report.error);
+    assert_eq!(report.answer.as_deref(), Some("11"));
+    assert_eq!(report.input, "sample.dat");
+}
+
+#[test]
+fn stops_a_solution_that_never_finishes() {
+    let limits = Limits {
+        timeout: Some(Duration::from_millis(500)),
+        memory: None,
+    };
+    let start = Instant::now();
+    let report = run_isolated(program(), limits, 0, 6, Part::One, CYCLING_MAP, "cycle.dat");
+    assert!(start.elapsed() < Duration::from_secs(30));
+    assert_eq!(report.status, ReportStatus::TimedOut);
+    assert_eq!(report.error.as_deref(), Some("timed out after 500ms"));
+}
+
+#[cfg(unix)]
+#[test]
+fn stops_a_solution_that_outgrows_its_memory() {
+    let mut input = String::new();
+    for i in 0..200_000 {
+        writeln!(input, "{}   {}", i, 200_000 - i).unwrap();
+    }
+    let limits = Limits {
+        timeout: Some(Duration::from_secs(60)),
+        memory: Some(1 << 20),
+    };
+    let report = run_isolated(program(), limits, 0, 1, Part::One, &input, "large.dat");
+    assert_eq!(report.status, ReportStatus::ExceededMemory, "{:?}", report);
+    assert_eq!(
+        report.error.as_deref(),
+        Some("exceeded the memory limit of 1M")
+    );
+}
+
+#[test]
+fn reports_a_failing_solution() {
+    let report = run_isolated(
+        program(),
+        Limits {
+            timeout: Some(Duration::from_secs(60)),
+            memory: None,
+        },
+        0,
+        1,
+        Part::One,
+        "not numbers\n",
+        "bad.dat",
+    );
+    assert_eq!(report.status, ReportStatus::Failed);
+    assert!(report.error.is_some());
+}
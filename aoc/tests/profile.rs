@@ -0,0 +1,54 @@
+// Checks run --profile reports the allocations of each phase, also from an isolated child
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use aoc_core::Report;
+
+fn run(args: &[&str]) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run",
+            "--day",
+            "1",
+            "--part",
+            "1",
+            "--input",
+            "-",
+            "--profile",
+        ])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn prints_allocations_after_the_answer() {
+    let stdout = run(&[]);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3, "{}", stdout);
+    assert_eq!(lines[0], "Day 1 Part 1: 11");
+    assert!(lines[1].starts_with("  parse "), "{}", stdout);
+    assert!(lines[1].contains(" allocations, "), "{}", stdout);
+    assert!(lines[2].starts_with("  solve "), "{}", stdout);
+}
+
+#[test]
+fn counts_allocations_in_isolated_solutions() {
+    let stdout = run(&["--timeout", "60s", "--format", "json"]);
+    let reports: Vec<Report> = serde_json::from_str(&stdout).unwrap();
+    let parse = reports[0].parse_alloc.expect("parsing was profiled");
+    assert!(parse.count > 0);
+    assert!(parse.bytes >= parse.peak_bytes);
+    assert!(reports[0].solve_alloc.is_some());
+}
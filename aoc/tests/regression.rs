@@ -0,0 +1,95 @@
+// Runs every solution end to end against the files in input/ and the answers in answers.toml
+use std::path::{Path, PathBuf};
+
+use aoc::answers::Answers;
+use aoc::input::discover_inputs;
+use aoc::verify::{verify_record, Status};
+use aoc_crypt::{is_encrypted, is_sample, locate_input, Key};
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn answers() -> Answers {
+    Answers::load(&workspace_root().join("answers.toml")).expect("answers.toml must load")
+}
+
+#[test]
+fn every_input_file_has_a_recorded_answer() {
+    let answers = answers();
+    let inputs = discover_inputs(&workspace_root().join("input")).expect("input/ must be readable");
+    assert!(!inputs.is_empty());
+
+    let unrecorded: Vec<&String> = inputs
+        .iter()
+        .map(|(file_name, _)| file_name)
+        .filter(|file_name| !answers.records.iter().any(|r| &r.input == *file_name))
+        .collect();
+    assert!(
+        unrecorded.is_empty(),
+        "input files without an entry in answers.toml: {:?}",
+        unrecorded
+    );
+}
+
+// what checking every recorded answer found; inputs are skipped when they are encrypted and no
+// key is available
+struct Checked {
+    checked: usize,
+    skipped: Vec<String>,
+    failures: Vec<String>,
+}
+
+fn check_answers(input_dir: &Path, have_key: bool) -> Checked {
+    let mut checked = Checked {
+        checked: 0,
+        skipped: Vec::new(),
+        failures: Vec::new(),
+    };
+    for record in answers().records.iter() {
+        let encrypted =
+            locate_input(&input_dir.join(&record.input)).is_some_and(|p| is_encrypted(&p));
+        if encrypted && !have_key {
+            checked.skipped.push(record.input.clone());
+            continue;
+        }
+        checked.checked += 1;
+        match verify_record(record, input_dir) {
+            Status::Pass => {}
+            // placeholders for days still being solved
+            Status::Missing(_) if record.answer.is_none() => {}
+            status => checked.failures.push(format!(
+                "day {} part {} {}: {}",
+                record.day, record.part, record.input, status
+            )),
+        }
+    }
+    checked
+}
+
+#[test]
+fn every_recorded_answer_matches() {
+    let input_dir = workspace_root().join("input");
+    // without the key, as in CI when the AOC_KEY secret is not configured, only the clear text
+    // samples can be checked
+    let have_key = Key::find(&input_dir).is_ok();
+    let checked = check_answers(&input_dir, have_key);
+    eprintln!(
+        "checked {} inputs, skipped {} encrypted without a key",
+        checked.checked,
+        checked.skipped.len()
+    );
+    assert!(
+        checked.failures.is_empty(),
+        "\n{}",
+        checked.failures.join("\n")
+    );
+    assert!(checked.checked > 0);
+    if have_key {
+        assert_eq!(checked.skipped, Vec::<String>::new());
+    } else {
+        let answers = answers();
+        let encrypted = answers.records.iter().filter(|r| !is_sample(&r.input));
+        assert_eq!(checked.skipped.len(), encrypted.count());
+    }
+}
@@ -0,0 +1,71 @@
+// Checks -v sends the solutions' events to stderr and leaves stdout to the answers
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const SAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...\n";
+
+fn run(args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "6", "--part", "1", "--input", "-"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(SAMPLE.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn quiet_without_verbose() {
+    let output = run(&[]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 6 Part 1: 41\n"
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn logs_phases_with_one_verbose() {
+    let output = run(&["-v"]);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 6 Part 1: 41\n"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("day{day=6 part=1}"), "{}", stderr);
+    assert!(stderr.contains("parsed"), "{}", stderr);
+    assert!(stderr.contains("solved"), "{}", stderr);
+    assert!(!stderr.contains("guard turns"), "{}", stderr);
+}
+
+#[test]
+fn logs_debug_events_with_two_verbose() {
+    let output = run(&["-vv"]);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 6 Part 1: 41\n"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("guard turns"), "{}", stderr);
+    assert!(stderr.contains("guard leaves the board"), "{}", stderr);
+}
+
+#[test]
+fn passes_verbose_to_isolated_solutions() {
+    let output = run(&["-v", "--timeout", "60s"]);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Day 6 Part 1: 41\n"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("solved"), "{}", stderr);
+}
@@ -0,0 +1,194 @@
+//! Advent of Code 2024, day 1: Historian Hysteria: comparing two lists of location ids
+
+use std::collections::HashMap;
+
+use aoc_core::{aoc, parse, AocError, ParseError, Solution};
+use itertools::izip;
+
+/// The two location id columns from the historians' lists
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationLists {
+    /// The ids of the left column, in input order
+    pub left: Vec<i64>,
+    /// The ids of the right column, in input order
+    pub right: Vec<i64>,
+}
+
+/// One pair of location ids per line, split into the left and right lists
+pub fn parse_location_lists(lines: &[&str]) -> Result<LocationLists, ParseError> {
+    let mut left: Vec<i64> = vec![];
+    let mut right: Vec<i64> = vec![];
+
+    for (i, line) in lines.iter().enumerate() {
+        let line_number = i + 1;
+        let ids = parse::numbers::<i64>(line_number, line)?;
+        let [l, r] = ids[..] else {
+            let column = line.trim_end().chars().count() + 1;
+            let reason = format!("expected two location ids, found {}", ids.len());
+            return Err(ParseError::new(line_number, column, line, reason));
+        };
+        left.push(l);
+        right.push(r);
+    }
+    Ok(LocationLists { left, right })
+}
+
+/// Total distance between the lists, pairing the smallest id on the left with the smallest on
+/// the right, and so on
+pub fn sum_differences(lists: &LocationLists) -> i64 {
+    let mut l1 = lists.left.clone();
+    let mut l2 = lists.right.clone();
+    l1.sort();
+    l2.sort();
+    let mut sum_distance = 0;
+    for (x1, x2) in izip!(l1.iter(), l2.iter()) {
+        sum_distance += (x1 - x2).abs();
+    }
+    sum_distance
+}
+
+/// Each left id multiplied by the number of times it appears on the right, added up
+pub fn sum_similarity_score(lists: &LocationLists) -> i64 {
+    let mut count_map = HashMap::new();
+    for x1 in lists.left.iter() {
+        count_map.entry(*x1).or_insert(0);
+    }
+    for x2 in lists.right.iter() {
+        let count = count_map.entry(*x2).or_insert(0);
+        *count += 1;
+    }
+    let mut sum_similarity = 0;
+    for x1 in lists.left.iter() {
+        let count = count_map.get(x1).unwrap();
+        sum_similarity += *count * x1;
+    }
+    sum_similarity
+}
+
+/// Day 1: Historian Hysteria
+pub struct Day01;
+
+#[aoc]
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = LocationLists;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_location_lists(&input.lines().collect::<Vec<&str>>())?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(sum_differences(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(sum_similarity_score(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::sample_test;
+    use proptest::prelude::*;
+
+    sample_test!(day = 1, part = 1, file = "day1-part1-s1.dat", expect = 11);
+    sample_test!(day = 1, part = 2, file = "day1-part1-s1.dat", expect = 31);
+
+    #[test]
+    fn test_parse_location_lists() {
+        let lists = parse_location_lists(&["1 8", "3   6"]).unwrap();
+        assert_eq!(lists.left, vec![1, 3]);
+        assert_eq!(lists.right, vec![8, 6]);
+    }
+
+    #[test]
+    fn test_parse_location_lists_invalid() {
+        assert_eq!(
+            parse_location_lists(&["1 2", "1"]),
+            Err(ParseError::new(
+                2,
+                2,
+                "1",
+                "expected two location ids, found 1"
+            ))
+        );
+        assert_eq!(
+            parse_location_lists(&["1   x"]),
+            Err(ParseError::new(1, 5, "x", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_sum_differences() {
+        let lists = parse_location_lists(&["1 8", "3 6", "5 4", "7 2"]).unwrap();
+        assert_eq!(sum_differences(&lists), 4);
+    }
+
+    #[test]
+    fn test_sum_differences_2() {
+        let lists =
+            parse_location_lists(&["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"]).unwrap();
+        assert_eq!(sum_differences(&lists), 11);
+    }
+
+    #[test]
+    fn test_sum_similarity_score() {
+        let lists = parse_location_lists(&["1 8", "3 6", "5 4", "7 2"]).unwrap();
+        assert_eq!(sum_similarity_score(&lists), 0);
+    }
+
+    #[test]
+    fn test_sum_similarity_score_2() {
+        let lists =
+            parse_location_lists(&["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"]).unwrap();
+        assert_eq!(sum_similarity_score(&lists), 31);
+    }
+
+    fn render(pairs: &[(i64, i64)], gap: usize) -> Vec<String> {
+        pairs
+            .iter()
+            .map(|(left, right)| format!("{}{}{}", left, " ".repeat(gap), right))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parse_round_trip(
+            pairs in prop::collection::vec(any::<(i64, i64)>(), 0..20),
+            gap in 1..4usize,
+        ) {
+            let lines = render(&pairs, gap);
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let lists = parse_location_lists(&lines).unwrap();
+            prop_assert_eq!(lists.left, pairs.iter().map(|p| p.0).collect::<Vec<_>>());
+            prop_assert_eq!(lists.right, pairs.iter().map(|p| p.1).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn prop_parse_never_panics(input in "[0-9 \\-\n]{0,60}|\\PC{0,60}") {
+            let _ = parse_location_lists(&input.lines().collect::<Vec<&str>>());
+        }
+
+        // distances are taken between the sorted lists, so the order of either list and which
+        // one is on the left do not matter
+        #[test]
+        fn prop_sum_differences_is_symmetric(
+            left in prop::collection::vec(0..100_000i64, 0..20),
+        ) {
+            let mut right = left.clone();
+            right.reverse();
+            let same = LocationLists { left: left.clone(), right };
+            prop_assert_eq!(sum_differences(&same), 0);
+            let shifted = LocationLists {
+                left: left.iter().map(|id| id + 1).collect(),
+                right: left.clone(),
+            };
+            let swapped = LocationLists { left: shifted.right.clone(), right: shifted.left.clone() };
+            prop_assert_eq!(sum_differences(&shifted), left.len() as i64);
+            prop_assert_eq!(sum_differences(&swapped), left.len() as i64);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Advent of Code 2024, day 2: Red-Nosed Reports: checking that reactor levels change safely
+
+use std::cmp::Ordering;
+
+use aoc_core::{aoc, parse, AocError, ParseError, Solution};
+
+/// Part 1: counting the reports that are safe as they are
+pub mod part1;
+/// Part 2: counting the reports the problem dampener makes safe
+pub mod part2;
+
+const MAX_SAFE_DIFFERENCE: i64 = 3;
+
+/// One report of levels per line
+pub fn parse_reports(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let reports = parse::number_rows::<i64>(input)?;
+    if let Some(i) = reports.iter().position(Vec::is_empty) {
+        let line = input.lines().nth(i).unwrap_or_default();
+        return Err(ParseError::new(
+            i + 1,
+            1,
+            line,
+            "expected at least one level",
+        ));
+    }
+    Ok(reports)
+}
+
+/// Adjacent levels must differ by at least one and at most three
+pub fn is_safe_difference(difference: i64) -> bool {
+    (1..=MAX_SAFE_DIFFERENCE).contains(&difference.abs())
+}
+
+/// Less when the levels only increase, Greater when they only decrease
+pub fn is_monotonic(numbers: &[i64]) -> Option<Ordering> {
+    if numbers.windows(2).all(|w| w[0] < w[1]) {
+        Some(Ordering::Less)
+    } else if numbers.windows(2).all(|w| w[0] > w[1]) {
+        Some(Ordering::Greater)
+    } else {
+        None
+    }
+}
+
+/// A report is safe when its levels move in one direction by safe differences
+pub fn is_safe(numbers: &[i64]) -> bool {
+    if is_monotonic(numbers).is_some() {
+        numbers.windows(2).all(|w| is_safe_difference(w[1] - w[0]))
+    } else {
+        false
+    }
+}
+
+/// Day 2: Red-Nosed Reports
+pub struct Day02;
+
+#[aoc]
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_reports(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(part1::count_safe_reports(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(part2::count_safe_reports(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::sample_test;
+    use proptest::prelude::*;
+
+    sample_test!(day = 2, part = 1, file = "day2-part1-s1.dat", expect = 2);
+    sample_test!(day = 2, part = 2, file = "day2-part1-s1.dat", expect = 4);
+
+    #[test]
+    fn test_is_safe_difference_negative() {
+        assert!(is_safe_difference(-1));
+        assert!(is_safe_difference(-2));
+        assert!(is_safe_difference(-3));
+        assert!(!is_safe_difference(-4));
+    }
+
+    #[test]
+    fn test_is_safe_difference_positive() {
+        assert!(!is_safe_difference(0));
+        assert!(is_safe_difference(1));
+        assert!(is_safe_difference(2));
+        assert!(is_safe_difference(3));
+        assert!(!is_safe_difference(4));
+    }
+
+    #[test]
+    fn test_is_monotonic() {
+        assert_eq!(is_monotonic(&[1, 2, 3]), Some(Ordering::Less));
+        assert_eq!(is_monotonic(&[3, 2, 1]), Some(Ordering::Greater));
+        assert_eq!(is_monotonic(&[1, 2, 1]), None);
+        assert_eq!(is_monotonic(&[1, 1, 1]), None);
+    }
+
+    #[test]
+    fn test_is_increasing() {
+        let increasing = |numbers: &[i64]| is_monotonic(numbers) == Some(Ordering::Less);
+        assert!(increasing(&[1, 2, 3, 4, 5]));
+        assert!(!increasing(&[1, 2, 3, 3, 5]));
+        assert!(!increasing(&[1, 2, 3, 2, 5]));
+        assert!(!increasing(&[1, 2, 3, 4, 3]));
+        assert!(!increasing(&[1, 2, 2, 4, 5]));
+    }
+
+    #[test]
+    fn test_is_decreasing() {
+        let decreasing = |numbers: &[i64]| is_monotonic(numbers) == Some(Ordering::Greater);
+        assert!(decreasing(&[5, 4, 3, 2, 1]));
+        assert!(!decreasing(&[5, 4, 3, 3, 1]));
+        assert!(!decreasing(&[5, 4, 3, 2, 3]));
+        assert!(!decreasing(&[5, 4, 3, 2, 4]));
+        assert!(!decreasing(&[5, 4, 4, 2, 1]));
+    }
+
+    #[test]
+    fn test_is_safe() {
+        assert!(is_safe(&[1, 2, 3]));
+        assert!(is_safe(&[3, 2, 1]));
+        assert!(!is_safe(&[1, 5, 5]));
+    }
+
+    #[test]
+    fn test_empty_report() {
+        assert_eq!(is_monotonic(&[]), Some(Ordering::Less));
+        assert!(is_safe(&[]));
+    }
+
+    #[test]
+    fn test_parse_reports() {
+        let reports = parse_reports("1 2 3\n 4  5\n").unwrap();
+        assert_eq!(reports, vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn test_parse_reports_invalid() {
+        assert_eq!(
+            parse_reports("1 2 3\n1 2 x"),
+            Err(ParseError::new(2, 5, "x", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_empty_line() {
+        assert_eq!(
+            parse_reports("1 2 3\n\n4 5"),
+            Err(ParseError::new(2, 1, "", "expected at least one level"))
+        );
+    }
+
+    fn render(reports: &[Vec<i64>]) -> String {
+        let lines: Vec<String> = reports
+            .iter()
+            .map(|report| {
+                let levels: Vec<String> = report.iter().map(i64::to_string).collect();
+                levels.join(" ")
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    fn reports(levels: impl Strategy<Value = i64> + Clone) -> impl Strategy<Value = Vec<Vec<i64>>> {
+        prop::collection::vec(prop::collection::vec(levels, 1..8), 0..10)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parse_round_trip(reports in reports(any::<i64>())) {
+            prop_assert_eq!(parse_reports(&render(&reports)), Ok(reports));
+        }
+
+        #[test]
+        fn prop_parse_never_panics(input in "[0-9 \\-\n]{0,60}|\\PC{0,60}") {
+            let _ = parse_reports(&input);
+        }
+
+        // the problem dampener only ever rescues reports, so part 2 counts every safe report
+        #[test]
+        fn prop_dampener_keeps_safe_reports(reports in reports(0..12i64)) {
+            for report in reports.iter().filter(|report| is_safe(report)) {
+                prop_assert!(part2::is_safe_or_is_safe_after_removing_one_sample(report));
+            }
+            prop_assert!(part2::count_safe_reports(&reports) >= part1::count_safe_reports(&reports));
+        }
+    }
+}
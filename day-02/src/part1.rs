@@ -0,0 +1,52 @@
+use crate::is_safe;
+
+/// Reports whose levels are safe as they are
+pub fn count_safe_reports(reports: &[Vec<i64>]) -> i64 {
+    let mut safe_lines = 0;
+    for number_vec in reports {
+        if is_safe(number_vec) {
+            safe_lines += 1;
+        }
+    }
+    safe_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::parse;
+
+    use super::*;
+    use crate::parse_reports;
+
+    const SAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+    #[test]
+    fn test_count_safe_reports() {
+        let reports = parse_reports(SAMPLE).unwrap();
+        assert_eq!(count_safe_reports(&reports), 2);
+    }
+
+    #[test]
+    fn test_count_safe_line_check() {
+        let lines = [
+            "7 6 4 2 1".to_string(),
+            "1 2 7 8 9".to_string(),
+            "9 7 6 2 1".to_string(),
+            "1 3 2 4 5".to_string(),
+            "8 6 4 4 1".to_string(),
+            "1 3 6 7 9".to_string(),
+        ];
+        let expect = [true, false, false, false, false, true];
+        for (line, &expect) in lines.iter().zip(expect.iter()) {
+            let number_vec = parse::numbers(1, line).unwrap();
+            assert_eq!(is_safe(&number_vec), expect);
+        }
+    }
+
+    #[test]
+    fn test_broken_line() {
+        let line = "9 7 6 2 1".to_string();
+        let number_vec = parse::numbers(1, &line).unwrap();
+        assert!(!is_safe(&number_vec));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::is_safe;
+
+/// Safe as it is, or once any one level is removed
+pub fn is_safe_or_is_safe_after_removing_one_sample(numbers: &[i64]) -> bool {
+    if is_safe(numbers) {
+        return true;
+    }
+
+    for i in 0..numbers.len() {
+        if is_safe(&[&numbers[..i], &numbers[i + 1..]].concat()) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Reports that are safe, or would be with one level removed
+pub fn count_safe_reports(reports: &[Vec<i64>]) -> i64 {
+    reports
+        .iter()
+        .filter(|nums| is_safe_or_is_safe_after_removing_one_sample(nums))
+        .count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_reports;
+
+    const SAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+    #[test]
+    fn test_is_safe_or_is_safe_after_removing_one_sample() {
+        assert!(is_safe_or_is_safe_after_removing_one_sample(&[1, 2, 3]));
+        assert!(is_safe_or_is_safe_after_removing_one_sample(&[3, 2, 1]));
+        assert!(is_safe_or_is_safe_after_removing_one_sample(&[1, 2, 4]));
+        assert!(is_safe_or_is_safe_after_removing_one_sample(&[1, 5, 2]));
+        assert!(!is_safe_or_is_safe_after_removing_one_sample(&[1, 5, 5, 2]));
+    }
+
+    #[test]
+    fn test_count_safe_reports() {
+        let reports = parse_reports(SAMPLE).unwrap();
+        assert_eq!(count_safe_reports(&reports), 4);
+    }
+}
@@ -0,0 +1,124 @@
+use logos::Logos;
+use std::num::ParseIntError;
+use thiserror::Error;
+
+/// The instructions found in corrupted memory; everything else is skipped
+#[derive(Logos, Debug, PartialEq)]
+pub enum Token {
+    /// A `mul(a,b)` instruction
+    #[regex(r"mul\(\d+,\d+\)")]
+    Mul,
+    /// `do()`, which enables the muls that follow
+    #[regex(r"do\(\)")]
+    Do,
+    /// `don't()`, which disables the muls that follow
+    #[regex(r"don't\(\)")]
+    Dont,
+}
+
+/// Why a mul instruction could not be evaluated
+#[derive(Debug, Error)]
+pub enum ParseError {
+    /// Not of the form `mul(a,b)`
+    #[error("Parse Error")]
+    InvalidExpression,
+    /// A factor is not a number
+    #[error("Parse Int Error: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+    /// A product, or the sum of the products, does not fit in an i64
+    #[error("Overflow Error")]
+    Overflow,
+}
+
+/// The two factors of a mul(a,b) instruction
+pub fn parse_mul_expression(expression: &str) -> Result<(i64, i64), ParseError> {
+    // Check for proper prefix and suffix
+    if !expression.starts_with("mul(") || !expression.ends_with(")") {
+        return Err(ParseError::InvalidExpression);
+    }
+
+    // Remove "mul(" and ")" from the expression
+    let params = expression.trim_start_matches("mul(").trim_end_matches(")");
+
+    // Split the parameters
+    let mut split = params.split(",");
+
+    // Parse the first and second numbers
+    let a = split
+        .next()
+        .ok_or(ParseError::InvalidExpression)?
+        .trim()
+        .parse::<i64>()
+        .map_err(ParseError::InvalidNumber)?;
+    let b = split
+        .next()
+        .ok_or(ParseError::InvalidExpression)?
+        .trim()
+        .parse::<i64>()
+        .map_err(ParseError::InvalidNumber)?;
+
+    // Check for any unexpected extra parts
+    if split.next().is_some() {
+        return Err(ParseError::InvalidExpression);
+    }
+
+    Ok((a, b))
+}
+
+/// Add up the products of the mul instructions in memory; when conditional, don't() disables the
+/// muls that follow and do() enables them again
+pub fn sum_products(memory: &str, conditional: bool) -> Result<i64, ParseError> {
+    let mut current_sum: i64 = 0;
+    let mut is_product_enabled = true;
+    let mut lexer = Token::lexer(memory);
+    while let Some(token) = lexer.next() {
+        match token {
+            Ok(Token::Mul) if is_product_enabled => {
+                let expression = lexer.slice();
+                let (a, b) = parse_mul_expression(expression)?;
+                let product = a.checked_mul(b).ok_or(ParseError::Overflow)?;
+                current_sum = current_sum
+                    .checked_add(product)
+                    .ok_or(ParseError::Overflow)?;
+            }
+            Ok(Token::Do) => {
+                is_product_enabled = true;
+            }
+            Ok(Token::Dont) if conditional => {
+                is_product_enabled = false;
+            }
+            _ => { /* Ignore other tokens */ }
+        }
+    }
+
+    Ok(current_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mul_expression() {
+        assert_eq!(parse_mul_expression("mul(2,4)").unwrap(), (2, 4));
+        assert!(matches!(
+            parse_mul_expression("mul(2,4"),
+            Err(ParseError::InvalidExpression)
+        ));
+        assert!(matches!(
+            parse_mul_expression("mul(2,4,6)"),
+            Err(ParseError::InvalidExpression)
+        ));
+        assert!(matches!(
+            parse_mul_expression("mul(2,x)"),
+            Err(ParseError::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_sum_products_conditional() {
+        let memory = "mul(1,2)don't()mul(3,4)do()mul(5,6)";
+        assert_eq!(sum_products(memory, false).unwrap(), 44);
+        assert_eq!(sum_products(memory, true).unwrap(), 32);
+    }
+}
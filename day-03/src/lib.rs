@@ -0,0 +1,168 @@
+//! Advent of Code 2024, day 3: Mull It Over: evaluating the mul instructions in corrupted memory
+
+use aoc_core::{aoc, AocError, ParseError, Solution};
+use logos::Logos;
+
+/// The instructions in corrupted memory and how to evaluate them
+pub mod instruction;
+/// Part 1: adding up every mul instruction
+pub mod part1;
+/// Part 2: adding up the mul instructions that do() and don't() leave enabled
+pub mod part2;
+
+use instruction::{parse_mul_expression, Token};
+
+/// Join the lines of corrupted memory, checking every mul instruction parses
+pub fn parse_memory(input: &str) -> Result<String, ParseError> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let memory = lines.concat();
+    let mut lexer = Token::lexer(&memory);
+    while let Some(token) = lexer.next() {
+        if let Ok(Token::Mul) = token {
+            if let Err(e) = parse_mul_expression(lexer.slice()) {
+                let (line, column) = locate(&lines, lexer.span().start);
+                return Err(ParseError::new(line, column, lexer.slice(), e));
+            }
+        }
+    }
+    Ok(memory)
+}
+
+// line and column of an offset into the joined lines
+fn locate(lines: &[&str], offset: usize) -> (usize, usize) {
+    let mut start = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if offset < start + line.len() {
+            return (i + 1, line[..offset - start].chars().count() + 1);
+        }
+        start += line.len();
+    }
+    (lines.len().max(1), 1)
+}
+
+// a scan failing on memory that parsed means an instruction the parser let through
+impl From<instruction::ParseError> for AocError {
+    fn from(e: instruction::ParseError) -> Self {
+        AocError::Input(e.to_string())
+    }
+}
+
+/// Day 3: Mull It Over
+pub struct Day03;
+
+#[aoc]
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    // the corrupted memory with line breaks removed
+    type Input = String;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_memory(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(part1::scan_forward_and_multiply(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(part2::scan_forward_and_multiply(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::sample_test;
+    use proptest::prelude::*;
+
+    sample_test!(day = 3, part = 1, file = "day3-part1-s1.dat", expect = 161);
+    sample_test!(day = 3, part = 2, file = "day3-part2-s1.dat", expect = 48);
+
+    #[test]
+    fn test_parse_joins_lines() {
+        let memory = Day03::parse(
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]t\nhen(mul(11,8)mul(8,5))\n",
+        )
+        .unwrap();
+        assert_eq!(Day03::part1(&memory).unwrap(), 161);
+    }
+
+    #[test]
+    fn test_parse_memory_overflow() {
+        let e = parse_memory("mul(1,2)\nxxmul(99999999999999999999,2)").unwrap_err();
+        assert_eq!(e.line, 2);
+        assert_eq!(e.column, 3);
+        assert_eq!(e.text, "mul(99999999999999999999,2)");
+    }
+
+    #[test]
+    fn test_locate() {
+        let lines = ["abc", "", "de"];
+        assert_eq!(locate(&lines, 0), (1, 1));
+        assert_eq!(locate(&lines, 2), (1, 3));
+        assert_eq!(locate(&lines, 3), (3, 1));
+        assert_eq!(locate(&lines, 4), (3, 2));
+    }
+
+    // an instruction with its value, or filler that cannot form one
+    fn pieces() -> impl Strategy<Value = Vec<(String, Option<i64>)>> {
+        let piece = prop_oneof![
+            (0..1000i64, 0..1000i64).prop_map(|(a, b)| (format!("mul({},{})", a, b), Some(a * b))),
+            Just(("do()".to_string(), None)),
+            Just(("don't()".to_string(), None)),
+            "[a-ce-ln-z0-9 ,()!?\n]{1,6}".prop_map(|junk| (junk, None)),
+        ];
+        prop::collection::vec(piece, 0..40)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_scanners_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+            let input = String::from_utf8_lossy(&bytes);
+            if let Ok(memory) = parse_memory(&input) {
+                let _ = part1::scan_forward_and_multiply(&memory);
+                let _ = part2::scan_forward_and_multiply(&memory);
+            }
+        }
+
+        #[test]
+        fn prop_instructions_never_panic(input in "(mul\\([0-9]{1,24},[0-9]{1,24}\\)|do\\(\\)|don't\\(\\)|[mul(),0-9]){0,30}") {
+            if let Ok(memory) = parse_memory(&input) {
+                let _ = part1::scan_forward_and_multiply(&memory);
+                let _ = part2::scan_forward_and_multiply(&memory);
+            }
+        }
+
+        #[test]
+        fn prop_mul_expression_round_trip(a in any::<i64>(), b in any::<i64>()) {
+            let expression = format!("mul({},{})", a, b);
+            prop_assert_eq!(parse_mul_expression(&expression).unwrap(), (a, b));
+        }
+
+        #[test]
+        fn prop_scanners_match_reference(pieces in pieces()) {
+            let input: String = pieces.iter().map(|(text, _)| text.as_str()).collect();
+            let mut all = 0;
+            let mut enabled_sum = 0;
+            let mut enabled = true;
+            for (text, value) in pieces.iter() {
+                match (text.as_str(), value) {
+                    ("do()", _) => enabled = true,
+                    ("don't()", _) => enabled = false,
+                    (_, Some(value)) => {
+                        all += value;
+                        if enabled {
+                            enabled_sum += value;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let memory = parse_memory(&input).unwrap();
+            prop_assert_eq!(part1::scan_forward_and_multiply(&memory).unwrap(), all);
+            prop_assert_eq!(part2::scan_forward_and_multiply(&memory).unwrap(), enabled_sum);
+        }
+    }
+}
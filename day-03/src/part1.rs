@@ -0,0 +1,53 @@
+use crate::instruction::{sum_products, ParseError};
+
+/// Every mul instruction's product, added up
+pub fn scan_forward_and_multiply(input: &str) -> Result<i64, ParseError> {
+    sum_products(input, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow() {
+        let result = scan_forward_and_multiply("mul(9999999999,9999999999)");
+        assert!(matches!(result, Err(ParseError::Overflow)));
+    }
+
+    #[test]
+    fn test_sample() {
+        let sample = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let result = scan_forward_and_multiply(sample);
+        let expect = 161;
+        assert!(result.is_ok(), "Error: {:?}", result.err());
+        assert_eq!(result.unwrap(), expect);
+    }
+
+    #[test]
+    fn test_simple() {
+        let sample = "xmul(2,4)";
+        let result = scan_forward_and_multiply(sample);
+        let expect = 8;
+        assert!(result.is_ok(), "Error: {:?}", result.err());
+        assert_eq!(result.unwrap(), expect);
+    }
+
+    #[test]
+    fn test_multi() {
+        let sample = "xmul(2,4)%&mul[3,7]";
+        let result = scan_forward_and_multiply(sample);
+        let expect = 8;
+        assert!(result.is_ok(), "Error: {:?}", result.err());
+        assert_eq!(result.unwrap(), expect);
+    }
+
+    #[test]
+    fn test_bad_nesting() {
+        let sample = "xmul(2,4)mul%&mul[3,7])";
+        let result = scan_forward_and_multiply(sample);
+        let expect = 8;
+        assert!(result.is_ok(), "Error: {:?}", result.err());
+        assert_eq!(result.unwrap(), expect);
+    }
+}
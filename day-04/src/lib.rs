@@ -1 +1,139 @@
-pub mod lettermap;
+//! Advent of Code 2024, day 4: Ceres Search: finding XMAS in a word search
+
+use aoc_core::{aoc, AocError, Layout, ParseError, Solution};
+use aoc_grid::Grid;
+
+/// Part 1: finding a word in any of the eight directions
+pub mod part1;
+/// Part 2: finding a word crossing itself in the shape of an X
+pub mod part2;
+
+/// Rows of the word search, which must all have the width of the first row
+pub fn parse_letter_map(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_with(input, |c| {
+        if c.is_ascii_alphabetic() {
+            Ok(c)
+        } else {
+            Err("expected an ASCII letter")
+        }
+    })
+}
+
+/// Occurrences of XMAS in any of the eight directions
+pub fn count_xmas(map: &Grid<char>) -> u64 {
+    part1::search(map, "XMAS").unwrap_or(0)
+}
+
+/// MAS crossing itself diagonally in the shape of an X
+pub fn count_x_mas(map: &Grid<char>) -> u64 {
+    part2::search(map, "MAS").unwrap_or(0)
+}
+
+/// Day 4: Ceres Search
+pub struct Day04;
+
+#[aoc]
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const LAYOUT: Layout = Layout::Grid;
+
+    type Input = Grid<char>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_letter_map(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(count_xmas(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(count_x_mas(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::sample_test;
+    use proptest::prelude::*;
+
+    sample_test!(day = 4, part = 1, file = "day4-part1-s1.dat", expect = 18);
+    sample_test!(day = 4, part = 2, file = "day4-part1-s1.dat", expect = 9);
+
+    #[test]
+    fn test_parse_ragged() {
+        assert_eq!(
+            parse_letter_map("XMAS\nXMASX\n"),
+            Err(ParseError::new(
+                2,
+                5,
+                "XMASX",
+                "expected 4 columns, found 5"
+            ))
+        );
+        assert_eq!(
+            parse_letter_map("XMAS\nXM\n"),
+            Err(ParseError::new(2, 3, "XM", "expected 4 columns, found 2"))
+        );
+    }
+
+    #[test]
+    fn test_parse_non_ascii() {
+        assert_eq!(
+            parse_letter_map("XMAS\nXMÄS\n"),
+            Err(ParseError::new(2, 3, "Ä", "expected an ASCII letter"))
+        );
+    }
+
+    #[test]
+    fn test_parse_non_letter() {
+        assert_eq!(
+            parse_letter_map(
+                "XMAS
+X#AS
+"
+            ),
+            Err(ParseError::new(2, 2, "#", "expected an ASCII letter"))
+        );
+        assert_eq!(
+            parse_letter_map(
+                "XMA5
+"
+            ),
+            Err(ParseError::new(1, 4, "5", "expected an ASCII letter"))
+        );
+    }
+
+    fn letter_maps() -> impl Strategy<Value = Grid<char>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::sample::select(vec!['X', 'M', 'A', 'S']),
+                width * height,
+            )
+            .prop_map(move |cells| Grid::from_cells(width, height, cells).unwrap())
+        })
+    }
+
+    proptest! {
+        // words are found in all eight directions, so turning or mirroring the map finds the same
+        #[test]
+        fn prop_search_is_symmetric(map in letter_maps()) {
+            let words = part1::search(&map, "XMAS");
+            let crosses = part2::search(&map, "MAS");
+            for turned in [map.rotate_right(), map.transpose(), map.flip_horizontal()] {
+                prop_assert_eq!(part1::search(&turned, "XMAS"), words);
+                prop_assert_eq!(part2::search(&turned, "MAS"), crosses);
+            }
+        }
+
+        #[test]
+        fn prop_parse_never_panics(input in "\\PC{0,60}") {
+            if let Ok(map) = parse_letter_map(&input) {
+                let _ = part1::search(&map, "XMAS");
+                let _ = part2::search(&map, "MAS");
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_grid::{Direction8, Grid};
+
+/// Count the occurrences of word in the grid, read up, down, forward, backward, or along
+/// either diagonal
+pub fn search(map: &Grid<char>, word: &str) -> Option<u64> {
+    let word_chars: Vec<char> = word.chars().collect();
+    let mut count = 0;
+
+    for start in map.positions() {
+        for direction in Direction8::iter() {
+            let mut letters = map.ray(start, direction.delta()).map(|point| map[point]);
+            if word_chars.iter().all(|&c| letters.next() == Some(c)) {
+                count += 1;
+            }
+        }
+    }
+
+    if count > 0 {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let test_string = "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuv";
+        let map = Grid::from_cells(10, 10, test_string.chars().collect()).unwrap();
+
+        assert_eq!(search(&map, "abc"), Some(3));
+        assert_eq!(search(&map, "def"), Some(3));
+        assert_eq!(search(&map, "ghi"), Some(3));
+        assert_eq!(search(&map, "jkl"), Some(3));
+        assert_eq!(search(&map, "mno"), Some(3));
+        assert_eq!(search(&map, "pqr"), Some(4));
+        assert_eq!(search(&map, "stu"), Some(3));
+    }
+
+    #[test]
+    fn test_search_not_found() {
+        let test_string = "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuv";
+        let map = Grid::from_cells(10, 10, test_string.chars().collect()).unwrap();
+
+        assert_eq!(search(&map, "vxw"), None);
+        assert_eq!(search(&map, "uwv"), None);
+        assert_eq!(search(&map, "tvu"), None);
+        assert_eq!(search(&map, "rts"), None);
+        assert_eq!(search(&map, "qsr"), None);
+    }
+
+    #[test]
+    fn test_example_search() {
+        let map_string = [
+            "MMMSXXMASM".to_string(),
+            "MSAMXMSMSA".to_string(),
+            "AMXSXMAAMM".to_string(),
+            "MSAMASMSMX".to_string(),
+            "XMASAMXAMM".to_string(),
+            "XXAMMXXAMA".to_string(),
+            "SMSMSASXSS".to_string(),
+            "SAXAMASAAA".to_string(),
+            "MAMMMXMMMM".to_string(),
+            "MXMXAXMASX".to_string(),
+        ]
+        .join("");
+        let map = Grid::from_cells(10, 10, map_string.chars().collect()).unwrap();
+        let word = "XMAS";
+        assert_eq!(search(&map, word), Some(18));
+    }
+}
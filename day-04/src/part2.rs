@@ -0,0 +1,131 @@
+use aoc_grid::{Direction8, Grid};
+
+/// Count the places where word crosses itself diagonally in the shape of an X
+///
+/// The word can be read either way along each diagonal, which gives four shapes for MAS:
+///
+/// ```text
+/// down right and up right   down left and down right
+/// M . S                     S . M
+/// . A .                     . A .
+/// M . S                     S . M
+///
+/// down right and up left    up right and down left
+/// M . M                     S . S
+/// . A .                     . A .
+/// S . S                     M . M
+/// ```
+pub fn search(map: &Grid<char>, word: &str) -> Option<u64> {
+    let n = word.len();
+    if n == 0 {
+        return None;
+    }
+    // An X pattern requires a center. If word length is even, no single center exists.
+    if n.is_multiple_of(2) {
+        return None;
+    }
+
+    let mid = n / 2;
+    let wchars: Vec<char> = word.chars().collect();
+    let revchars: Vec<char> = wchars.iter().copied().rev().collect();
+
+    // the letters on the diagonal through center, read walking away from the start corner
+    let diagonal = |center, corner: Direction8| -> Option<Vec<char>> {
+        let start = map.step(center, corner.delta() * mid as isize)?;
+        let letters: Vec<char> = map
+            .ray(start, corner.reverse().delta())
+            .take(n)
+            .map(|point| map[point])
+            .collect();
+        (letters.len() == n).then_some(letters)
+    };
+    let is_word = |letters: &Vec<char>| *letters == wchars || *letters == revchars;
+
+    let mut match_count = 0;
+    for (center, &center_ch) in map.iter() {
+        // The center of the X must match the middle character of the word
+        if center_ch != wchars[mid] {
+            continue;
+        }
+        // both diagonals \ and / must read the word forward or backward
+        let backslash = diagonal(center, Direction8::UpLeft);
+        let slash = diagonal(center, Direction8::UpRight);
+        if backslash.as_ref().is_some_and(is_word) && slash.as_ref().is_some_and(is_word) {
+            match_count += 1;
+        }
+    }
+
+    Some(match_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search1() {
+        let test_string = "M.S.A.M.S";
+        let map = Grid::from_cells(3, 3, test_string.chars().collect()).unwrap();
+        assert_eq!(search(&map, "MAS"), Some(1));
+    }
+
+    #[test]
+    fn test_search2() {
+        let test_string = "S.M.A.S.M";
+        let map = Grid::from_cells(3, 3, test_string.chars().collect()).unwrap();
+        assert_eq!(search(&map, "MAS"), Some(1));
+    }
+
+    #[test]
+    fn test_search3() {
+        let test_string = "M.M.A.S.S";
+        let map = Grid::from_cells(3, 3, test_string.chars().collect()).unwrap();
+        assert_eq!(search(&map, "MAS"), Some(1));
+    }
+
+    #[test]
+    fn test_search4() {
+        let test_string = "S.S.A.M.M";
+        let map = Grid::from_cells(3, 3, test_string.chars().collect()).unwrap();
+        assert_eq!(search(&map, "MAS"), Some(1));
+    }
+
+    #[test]
+    fn test_search_no_mas() {
+        let test_string = "M.M.A.M.M";
+        let map = Grid::from_cells(3, 3, test_string.chars().collect()).unwrap();
+        assert_eq!(search(&map, "MAS"), Some(0));
+    }
+
+    #[test]
+    fn test_search_not_found() {
+        let test_string = "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuv";
+        let map = Grid::from_cells(10, 10, test_string.chars().collect()).unwrap();
+
+        assert_eq!(search(&map, "vxw"), Some(0));
+        assert_eq!(search(&map, "uwv"), Some(0));
+        assert_eq!(search(&map, "tvu"), Some(0));
+        assert_eq!(search(&map, "rts"), Some(0));
+        assert_eq!(search(&map, "qsr"), Some(0));
+    }
+
+    #[test]
+    fn test_example_search() {
+        let map_string = [
+            ".M.S......".to_string(),
+            "..A..MSMS.".to_string(),
+            ".M.S.MAA..".to_string(),
+            "..A.ASMSM.".to_string(),
+            ".M.S.M....".to_string(),
+            "..........".to_string(),
+            "S.S.S.S.S.".to_string(),
+            ".A.A.A.A..".to_string(),
+            "M.M.M.M.M.".to_string(),
+            "........A.".to_string(),
+        ]
+        .join("");
+        let map = Grid::from_cells(10, 10, map_string.chars().collect()).unwrap();
+        let word = "MAS";
+        assert_eq!(search(&map, word), Some(9));
+    }
+}
@@ -1,12 +1,21 @@
+//! Advent of Code 2024, day 5: Print Queue: checking and repairing the page order of updates
+
+/// Part 2: putting the updates that break the rules back in order
+pub mod repair;
+
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use aoc_core::{aoc, parse, AocError, ParseError, Solution};
+
+/// The pages printed before one page of an update
 #[derive(Debug, Default)]
 pub struct PageRelations {
     before: HashSet<usize>,
 }
 
 impl PageRelations {
+    /// Relations with no pages before
     pub fn new() -> Self {
         Self {
             before: HashSet::new(),
@@ -20,23 +29,26 @@ impl Default for PageOrderTable {
     }
 }
 
+/// For each page of an update, the pages printed before it
 #[derive(Debug)]
 pub struct PageOrderTable {
     pages: HashMap<usize, PageRelations>,
 }
 
 impl PageOrderTable {
+    /// A table with no pages
     pub fn new() -> Self {
         Self {
             pages: HashMap::new(),
         }
     }
 
+    /// Whether the update prints page
     pub fn contains(&self, page: usize) -> bool {
         self.pages.contains_key(&page)
     }
 
-    // Check if p1 is before p2
+    /// Whether page p1 is printed before page p2
     pub fn is_before(&self, p1: usize, p2: usize) -> bool {
         self.pages
             .get(&p2)
@@ -45,15 +57,11 @@ impl PageOrderTable {
     }
 }
 
-pub fn build_page_order_table(updates: &str) -> PageOrderTable {
+/// The order in which the update prints its pages
+pub fn build_page_order_table(update: &[usize]) -> PageOrderTable {
     let mut table = PageOrderTable::new();
-
-    let updates = updates
-        .split(',')
-        .flat_map(|s| s.parse())
-        .collect::<Vec<usize>>();
     let mut before_pages: HashSet<usize> = HashSet::new();
-    for page in updates {
+    for &page in update {
         let mut relation = PageRelations::new();
         relation.before = before_pages.clone();
         before_pages.insert(page);
@@ -62,58 +70,130 @@ pub fn build_page_order_table(updates: &str) -> PageOrderTable {
     table
 }
 
-pub fn split_rule(rule: &str) -> (usize, usize) {
-    let parts = rule.split('|').collect::<Vec<&str>>();
-    (parts[0].parse().unwrap(), parts[1].parse().unwrap())
+/// Every update reached by swapping two of its pages
+pub fn permute_update(update: &[usize]) -> Vec<Vec<usize>> {
+    let mut permutations = Vec::new();
+    for i in 0..update.len() {
+        for j in i + 1..update.len() {
+            let mut permutation = update.to_vec();
+            permutation.swap(i, j);
+            permutations.push(permutation);
+        }
+    }
+    permutations
 }
 
-pub fn split_rules_and_updates(lines: &[String]) -> (Vec<String>, Vec<String>) {
-    let mut rules = Vec::new();
-    let mut updates = Vec::new();
-    let mut is_rule = true;
-    for line in lines {
-        if line.is_empty() {
-            is_rule = false;
-            continue;
+/// The rules that name two pages of the update
+pub fn relevant_rules(table: &PageOrderTable, rules: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    rules
+        .iter()
+        .copied()
+        .filter(|&(a, b)| table.contains(a) && table.contains(b))
+        .collect()
+}
+
+/// Whether the update prints its pages in an order every rule allows
+pub fn check_rules_for_update(update: &[usize], rules: &[(usize, usize)]) -> bool {
+    let page_order_table = build_page_order_table(update);
+    relevant_rules(&page_order_table, rules)
+        .iter()
+        .all(|&(before, after)| page_order_table.is_before(before, after))
+}
+
+/// Page ordering rules, as (a, b) for a rule a|b where page a must be printed before page b,
+/// followed by the updates to print
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintQueue {
+    /// The ordering rules, in input order
+    pub rules: Vec<(usize, usize)>,
+    /// The pages of each update, in the order they are printed
+    pub updates: Vec<Vec<usize>>,
+}
+
+impl PrintQueue {
+    /// Rules and updates are separated by a blank line
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut sections = parse::sections(input).into_iter();
+        let mut rules = Vec::new();
+        for (line_number, line) in sections.next().iter().flat_map(|s| s.numbered_lines()) {
+            rules.push(parse_rule(line_number, line)?);
+        }
+        let mut updates = Vec::new();
+        for (line_number, line) in sections.next().iter().flat_map(|s| s.numbered_lines()) {
+            updates.push(parse::separated(line_number, line, ',').map_err(invalid_page)?);
         }
-        if is_rule {
-            rules.push(line.clone());
-        } else {
-            updates.push(line.clone());
+        if let Some(extra) = sections.next() {
+            return Err(ParseError::new(
+                extra.first_line,
+                1,
+                extra.lines[0],
+                "expected only rules and updates",
+            ));
         }
+        Ok(Self { rules, updates })
     }
-    (rules, updates)
 }
 
-pub fn permute_update(update: &str) -> Vec<String> {
-    let parts = update.split(',').collect::<Vec<&str>>();
-    let mut permutations = Vec::new();
-    for i in 0..parts.len() {
-        for j in i + 1..parts.len() {
-            let mut new_parts = parts.clone();
-            new_parts.swap(i, j);
-            permutations.push(new_parts.join(","));
-        }
+fn invalid_page(e: ParseError) -> ParseError {
+    ParseError {
+        reason: format!("invalid page: {}", e.reason),
+        ..e
     }
-    permutations
 }
 
-pub fn check_rules_for_line(line: &str, rules: &[String]) -> bool {
-    let page_order_table = build_page_order_table(line);
+/// A rule a|b as (a, b): page a must be printed before page b
+pub fn parse_rule(line_number: usize, line: &str) -> Result<(usize, usize), ParseError> {
+    let Some((before, after)) = line.split_once('|') else {
+        return Err(ParseError::new(
+            line_number,
+            1,
+            line,
+            "expected a rule of the form X|Y",
+        ));
+    };
+    Ok((
+        parse::number(line_number, line, before).map_err(invalid_page)?,
+        parse::number(line_number, line, after).map_err(invalid_page)?,
+    ))
+}
 
-    let relevant_rule: Vec<(usize, usize)> = rules
+/// Sum of the middle pages of the updates already in an order the rules allow
+pub fn sum_valid_middle_pages(queue: &PrintQueue) -> usize {
+    queue
+        .updates
         .iter()
-        .map(|r| split_rule(r))
-        .filter(|(a, b)| page_order_table.contains(*a) && page_order_table.contains(*b))
-        .collect();
+        .filter(|update| check_rules_for_update(update, &queue.rules))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
 
-    for rule in relevant_rule.iter() {
-        // Adjusted the call to is_before
-        if !page_order_table.is_before(rule.0, rule.1) {
-            return false;
-        }
+/// Sum of the middle pages of the updates that needed reordering, once reordered
+pub fn sum_repaired_updates(queue: &PrintQueue) -> Result<usize, AocError> {
+    repair::sum_repaired_middle_pages(queue)
+        .ok_or_else(|| AocError::Solve("Failed to repair update".to_string()))
+}
+
+/// Day 5: Print Queue
+pub struct Day05;
+
+#[aoc]
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = PrintQueue;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(PrintQueue::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(sum_valid_middle_pages(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        sum_repaired_updates(input)
     }
-    true
 }
 
 #[cfg(test)]
@@ -123,26 +203,31 @@ pub mod test_data;
 mod tests {
     use super::test_data::data_helper::sample_data;
     use super::*;
+    use aoc_core::sample_test;
+    use proptest::prelude::*;
+
+    sample_test!(day = 5, part = 1, file = "day5-part1-s1.dat", expect = 143);
+    sample_test!(day = 5, part = 2, file = "day5-part1-s1.dat", expect = 123);
+
+    fn sample_queue() -> PrintQueue {
+        PrintQueue::parse(&sample_data().join("\n")).unwrap()
+    }
 
     #[test]
     fn test_decode_rules_updates() {
-        let (rules, updates) = split_rules_and_updates(&sample_data());
-        rules.iter().for_each(|r| println!("{}", r));
-        assert_eq!(rules.len(), 21);
-        assert_eq!(updates.len(), 6);
+        let queue = sample_queue();
+        assert_eq!(queue.rules.len(), 21);
+        assert_eq!(queue.updates.len(), 6);
     }
 
     #[test]
     fn test_build_order_table() {
-        let (_, updates) = split_rules_and_updates(&sample_data());
-        for update in updates.iter() {
+        for update in sample_queue().updates.iter() {
             let table = build_page_order_table(update);
-            let pages = update.split(',').map(|s| s.parse().unwrap());
             let mut before_pages: HashSet<usize> = HashSet::new();
-            for page in pages {
+            for &page in update {
                 table.contains(page);
                 for bp in before_pages.iter() {
-                    println!("{} is before {}", bp, page);
                     assert!(table.is_before(*bp, page));
                 }
                 before_pages.insert(page);
@@ -151,37 +236,105 @@ mod tests {
     }
 
     #[test]
-    fn test_split_rule() {
-        assert_eq!(split_rule("47|53"), (47, 53));
+    fn test_parse_rule() {
+        assert_eq!(parse_rule(1, "47|53"), Ok((47, 53)));
+    }
+
+    #[test]
+    fn test_parse_rule_invalid() {
+        assert!(parse_rule(1, "47").is_err());
+        assert!(parse_rule(1, "47|x").is_err());
+    }
+
+    #[test]
+    fn test_parse_print_queue() {
+        let queue = PrintQueue::parse("47|53\n97|13\n\n75,47,61\n97,61\n").unwrap();
+        assert_eq!(queue.rules, vec![(47, 53), (97, 13)]);
+        assert_eq!(queue.updates, vec![vec![75, 47, 61], vec![97, 61]]);
+    }
+
+    #[test]
+    fn test_parse_extra_section() {
+        assert_eq!(
+            PrintQueue::parse("47|53\n\n47,53\n\n53,47"),
+            Err(ParseError::new(
+                5,
+                1,
+                "53,47",
+                "expected only rules and updates"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_rule() {
+        assert_eq!(
+            PrintQueue::parse("47|53\n47,53\n\n47,53"),
+            Err(ParseError::new(
+                2,
+                1,
+                "47,53",
+                "expected a rule of the form X|Y"
+            ))
+        );
+        assert_eq!(
+            PrintQueue::parse("47|5x\n\n47,53"),
+            Err(ParseError::new(
+                1,
+                4,
+                "5x",
+                "invalid page: invalid digit found in string"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_update() {
+        assert_eq!(
+            PrintQueue::parse("47|53\n\n47,53\n47,,53"),
+            Err(ParseError::new(
+                4,
+                4,
+                "",
+                "invalid page: cannot parse integer from empty string"
+            ))
+        );
     }
 
     #[test]
     fn test_check_valid_lines() {
-        let lines = ["75,47,61,53,29", "97,61,53,29,13", "75,29,13"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let (rules, _) = split_rules_and_updates(&sample_data());
-        for line in lines.iter() {
-            assert!(check_rules_for_line(line, &rules));
+        let updates = [
+            &[75, 47, 61, 53, 29][..],
+            &[97, 61, 53, 29, 13],
+            &[75, 29, 13],
+        ];
+        let queue = sample_queue();
+        for update in updates {
+            assert!(check_rules_for_update(update, &queue.rules));
         }
     }
 
     #[test]
     fn test_check_bad_lines() {
-        let lines = ["61,13,29", "75,97,47,61,53", "97,13,75,29,47"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>();
-        let (rules, _) = split_rules_and_updates(&sample_data());
-        for line in lines.iter() {
-            assert!(!check_rules_for_line(line, &rules));
+        let updates = [
+            &[61, 13, 29][..],
+            &[75, 97, 47, 61, 53],
+            &[97, 13, 75, 29, 47],
+        ];
+        let queue = sample_queue();
+        for update in updates {
+            assert!(!check_rules_for_update(update, &queue.rules));
         }
     }
 
+    #[test]
+    fn test_sum_valid_middle_pages() {
+        assert_eq!(sum_valid_middle_pages(&sample_queue()), 143);
+    }
+
     #[test]
     fn test_build_page_order_table() {
-        let table = build_page_order_table("75,47,61,53,29");
+        let table = build_page_order_table(&[75, 47, 61, 53, 29]);
         assert!(table.contains(75));
         assert!(table.contains(47));
         assert!(table.contains(61));
@@ -191,4 +344,79 @@ mod tests {
         // Adjusted the call to is_before
         assert!(table.is_before(47, 53)); // Now 47 is before 53
     }
+
+    const PAGES: std::ops::Range<usize> = 10..22;
+
+    // every page in a random order, and which of the rules that order implies to keep
+    fn orders() -> impl Strategy<Value = (Vec<usize>, Vec<bool>)> {
+        let pairs = PAGES.len() * (PAGES.len() - 1) / 2;
+        (
+            Just(PAGES.collect::<Vec<_>>()).prop_shuffle(),
+            prop::collection::vec(any::<bool>(), pairs),
+        )
+    }
+
+    fn updates() -> impl Strategy<Value = Vec<usize>> {
+        prop::sample::subsequence(PAGES.collect::<Vec<_>>(), 1..=PAGES.len()).prop_shuffle()
+    }
+
+    fn rules_from(order: &[usize], keep: &[bool]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for i in 0..order.len() {
+            for j in i + 1..order.len() {
+                pairs.push((order[i], order[j]));
+            }
+        }
+        pairs
+            .into_iter()
+            .zip(keep.iter())
+            .filter(|(_, &keep)| keep)
+            .map(|(rule, _)| rule)
+            .collect()
+    }
+
+    // no rule puts a later page of the update before an earlier one
+    fn naive_check(update: &[usize], rules: &[(usize, usize)]) -> bool {
+        rules.iter().all(|&(before, after)| {
+            match (
+                update.iter().position(|&p| p == before),
+                update.iter().position(|&p| p == after),
+            ) {
+                (Some(b), Some(a)) => b < a,
+                _ => true,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_check_rules_matches_reference((order, keep) in orders(), update in updates()) {
+            let rules = rules_from(&order, &keep);
+            prop_assert_eq!(
+                check_rules_for_update(&update, &rules),
+                naive_check(&update, &rules)
+            );
+        }
+
+        #[test]
+        fn prop_repair_follows_the_order((order, _) in orders(), update in updates()) {
+            let rules = rules_from(&order, &vec![true; order.len() * order.len()]);
+            let repaired = repair::repair_update(&update, &rules).unwrap();
+            let mut expected = update.clone();
+            expected.sort_by_key(|page| order.iter().position(|p| p == page));
+            prop_assert_eq!(repaired, expected);
+        }
+
+        #[test]
+        fn prop_parse_rule_round_trip(before in any::<usize>(), after in any::<usize>()) {
+            prop_assert_eq!(parse_rule(1, &format!("{}|{}", before, after)), Ok((before, after)));
+        }
+
+        #[test]
+        fn prop_parse_never_panics(input in "[0-9|,\n ]{0,80}") {
+            if let Ok(queue) = PrintQueue::parse(&input) {
+                let _ = sum_valid_middle_pages(&queue);
+            }
+        }
+    }
 }
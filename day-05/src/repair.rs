@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::warn;
+
+use crate::{build_page_order_table, check_rules_for_update, relevant_rules, PrintQueue};
+
+/// The nodes ordered so every edge points forward, None when the edges form a cycle
+pub fn topological_sort(nodes: &HashSet<usize>, edges: &[(usize, usize)]) -> Option<Vec<usize>> {
+    let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut in_degree: HashMap<usize, usize> = HashMap::new();
+
+    // Initialize in-degree for each node
+    for &node in nodes {
+        in_degree.insert(node, 0);
+    }
+
+    // Build graph and calculate in-degrees
+    for &(u, v) in edges {
+        graph.entry(u).or_default().push(v);
+        *in_degree.entry(v).or_default() += 1;
+    }
+
+    // Queue for nodes with in-degree 0
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for (&node, &degree) in &in_degree {
+        if degree == 0 {
+            queue.push_back(node);
+        }
+    }
+
+    let mut sorted_order = Vec::new();
+
+    while let Some(current) = queue.pop_front() {
+        sorted_order.push(current);
+
+        if let Some(neighbors) = graph.get(&current) {
+            for &neighbor in neighbors {
+                if let Some(degree) = in_degree.get_mut(&neighbor) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    // Check if sorting was successful
+    if sorted_order.len() == nodes.len() {
+        Some(sorted_order)
+    } else {
+        // Identify nodes involved in cycles
+        let remaining_nodes: HashSet<usize> = nodes
+            .iter()
+            .filter(|&node| !sorted_order.contains(node))
+            .cloned()
+            .collect();
+        warn(format!(
+            "Cycle detected involving nodes: {:?}",
+            remaining_nodes
+        ));
+        None // Cycle detected or invalid rules
+    }
+}
+
+/// The update's pages put in the order the rules give
+pub fn repair_update(update: &[usize], rules: &[(usize, usize)]) -> Option<Vec<usize>> {
+    // Collect all unique nodes
+    let mut nodes: HashSet<usize> = HashSet::new();
+    for &(u, v) in rules {
+        nodes.insert(u);
+        nodes.insert(v);
+    }
+
+    // Perform topological sort
+    let correct_order = topological_sort(&nodes, rules)?;
+    // Filter to include only nodes present in the update
+    Some(
+        correct_order
+            .into_iter()
+            .filter(|node| update.contains(node))
+            .collect(),
+    )
+}
+
+/// The middle pages of the updates that had to be repaired, added up
+pub fn sum_repaired_middle_pages(queue: &PrintQueue) -> Option<usize> {
+    let mut sum_of_valid_middle_page = 0;
+    for update in queue.updates.iter() {
+        if !check_rules_for_update(update, &queue.rules) {
+            let page_order_table = build_page_order_table(update);
+            let rules = relevant_rules(&page_order_table, &queue.rules);
+
+            if let Some(repaired_update) = repair_update(update, &rules) {
+                if repaired_update.is_empty() {
+                    warn("Repaired update is empty.");
+                    continue;
+                }
+                sum_of_valid_middle_page += repaired_update[repaired_update.len() / 2];
+            } else {
+                warn(format!("Failed to repair update: {:?}", update));
+                return None;
+            }
+        }
+    }
+    Some(sum_of_valid_middle_page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::data_helper::sample_data;
+
+    #[test]
+    fn test_topological_sort() {
+        // Define a sample graph with nodes and edges
+        let nodes: HashSet<usize> = [1, 2, 3, 4, 5].into_iter().collect();
+        let edges = vec![(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)];
+
+        // Perform topological sort
+        let result = topological_sort(&nodes, &edges);
+
+        // Verify the output
+        assert!(result.is_some()); // Ensure a valid result exists
+        let sorted_order = result.unwrap();
+        assert_eq!(sorted_order, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_topological_sort_with_cycle() {
+        // Define a cyclic graph
+        let nodes: HashSet<usize> = [1, 2, 3].into_iter().collect();
+        let edges = vec![
+            (1, 2),
+            (2, 3),
+            (3, 1), // Cycle: 3 -> 1
+        ];
+
+        // Perform topological sort
+        let result = topological_sort(&nodes, &edges);
+
+        // Verify that no valid result exists
+        assert!(result.is_none());
+        let warnings = aoc_core::report::take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Cycle detected"));
+    }
+
+    #[test]
+    fn test_topological_sort_empty_graph() {
+        // Define an empty graph
+        let nodes: HashSet<usize> = HashSet::new();
+        let edges = vec![];
+
+        // Perform topological sort
+        let result = topological_sort(&nodes, &edges);
+
+        // Verify the output for an empty graph
+        assert_eq!(result, Some(vec![])); // Empty graph should return an empty order
+    }
+
+    #[test]
+    fn test_repair_update() {
+        let rules = vec![(1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 8)];
+        let update = vec![8, 7, 6, 5, 4, 3, 2, 1];
+        let repaired_update = repair_update(&update, &rules).unwrap();
+        assert_eq!(repaired_update, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_sum_repaired_middle_pages() {
+        let queue = PrintQueue::parse(&sample_data().join("\n")).unwrap();
+        assert_eq!(sum_repaired_middle_pages(&queue), Some(123));
+    }
+}
@@ -0,0 +1,545 @@
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+use aoc_core::AocError;
+use aoc_grid::{Direction4, Grid, GridError, Point, Vec2};
+use thiserror::Error;
+use tracing::{debug, trace};
+
+const OBSTACLE: char = '#';
+const DOT: char = '.';
+
+#[derive(Debug, Clone, PartialEq)]
+enum GameBoardCell {
+    Guard(&'static Guard),
+    Obstacle(&'static Obstacle),
+    Dot(&'static Dot),
+}
+
+impl GameBoardCell {
+    fn get_representation(&self) -> char {
+        match self {
+            GameBoardCell::Guard(guard) => guard.get_representation(),
+            GameBoardCell::Obstacle(obstacle) => obstacle.get_representation(),
+            GameBoardCell::Dot(dot) => dot.get_representation(),
+        }
+    }
+
+    fn as_guard(&self) -> Option<&Guard> {
+        match self {
+            GameBoardCell::Guard(guard) => Some(guard),
+            _ => None,
+        }
+    }
+}
+
+impl Default for GameBoardCell {
+    fn default() -> Self {
+        GameBoardCell::Dot(&DOT_FLY_WEIGHT)
+    }
+}
+
+trait GamePiece {
+    fn get_representation(&self) -> char;
+}
+
+#[derive(Debug, Error)]
+enum GuardError {
+    #[error("Invalid guard representation")]
+    InvalidRepresentation,
+}
+
+const GUARD_FLY_WEIGHT_UP: Guard = Guard {
+    heading: Direction4::Up,
+};
+const GUARD_FLY_WEIGHT_RIGHT: Guard = Guard {
+    heading: Direction4::Right,
+};
+const GUARD_FLY_WEIGHT_DOWN: Guard = Guard {
+    heading: Direction4::Down,
+};
+const GUARD_FLY_WEIGHT_LEFT: Guard = Guard {
+    heading: Direction4::Left,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+struct Guard {
+    heading: Direction4,
+}
+
+impl GamePiece for Guard {
+    fn get_representation(&self) -> char {
+        self.heading.arrow()
+    }
+}
+
+impl Guard {
+    fn new(guard: char) -> Result<&'static Self, GuardError> {
+        Direction4::from_arrow(guard)
+            .map(Guard::facing)
+            .ok_or(GuardError::InvalidRepresentation)
+    }
+
+    fn facing(heading: Direction4) -> &'static Self {
+        match heading {
+            Direction4::Up => &GUARD_FLY_WEIGHT_UP,
+            Direction4::Right => &GUARD_FLY_WEIGHT_RIGHT,
+            Direction4::Down => &GUARD_FLY_WEIGHT_DOWN,
+            Direction4::Left => &GUARD_FLY_WEIGHT_LEFT,
+        }
+    }
+
+    fn is_guard(representation: char) -> bool {
+        Direction4::from_arrow(representation).is_some()
+    }
+
+    fn turn_right(&self) -> &'static Self {
+        Guard::facing(self.heading.turn_right())
+    }
+
+    fn get_delta(&self) -> Vec2 {
+        self.heading.delta()
+    }
+}
+
+const OBSTACLE_FLY_WEIGHT: Obstacle = Obstacle {};
+
+#[derive(Debug, PartialEq)]
+struct Obstacle {}
+
+impl Obstacle {
+    fn new() -> &'static Self {
+        &OBSTACLE_FLY_WEIGHT
+    }
+
+    fn is_obstacle(representation: char) -> bool {
+        representation == OBSTACLE
+    }
+}
+
+impl GamePiece for Obstacle {
+    fn get_representation(&self) -> char {
+        OBSTACLE
+    }
+}
+
+const DOT_FLY_WEIGHT: Dot = Dot {};
+
+#[derive(Debug, PartialEq)]
+struct Dot {}
+
+impl Dot {
+    fn new() -> &'static Self {
+        &DOT_FLY_WEIGHT
+    }
+
+    #[cfg(test)]
+    fn is_dot(representation: char) -> bool {
+        representation == DOT
+    }
+}
+
+impl GamePiece for Dot {
+    fn get_representation(&self) -> char {
+        DOT
+    }
+}
+
+/// The lab map: obstacles, open floor and the guard, with the positions the guard has visited
+#[derive(Clone)]
+pub struct GameBoard {
+    board: Grid<GameBoardCell>,
+    visited: HashSet<Point>,
+}
+
+impl GameBoard {
+    #[cfg(test)]
+    fn new(width: usize, height: usize) -> Self {
+        let board = Grid::new(width, height, GameBoardCell::default());
+        Self {
+            board,
+            visited: HashSet::new(),
+        }
+    }
+
+    /// One row of the board per line, with exactly one guard
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let mut guards = 0;
+        let board = Grid::parse_with(input, |cell| match cell {
+            cell if Guard::is_guard(cell) => {
+                guards += 1;
+                if guards > 1 {
+                    return Err("expected a single guard");
+                }
+                Ok(GameBoardCell::Guard(
+                    Guard::new(cell).expect("Invalid guard mapping"),
+                ))
+            }
+            '#' => Ok(GameBoardCell::Obstacle(Obstacle::new())),
+            '.' => Ok(GameBoardCell::Dot(Dot::new())),
+            _ => Err("invalid game piece"),
+        })?;
+        if board.height() == 0 {
+            return Err(AocError::Input("the board has no rows".to_string()));
+        }
+        if guards == 0 {
+            return Err(AocError::Input("the board has no guard".to_string()));
+        }
+        Ok(Self {
+            board,
+            visited: HashSet::new(),
+        })
+    }
+
+    fn set(&mut self, position: Point, piece: GameBoardCell) -> Result<(), GridError> {
+        self.board.set(position.x, position.y, piece)
+    }
+
+    fn get(&self, position: Point) -> Option<&GameBoardCell> {
+        self.board.get(position.x, position.y)
+    }
+
+    fn find_guard(&self) -> Option<Point> {
+        self.board
+            .find(|piece| Guard::is_guard(piece.get_representation()))
+    }
+
+    #[cfg(test)]
+    fn step_guard(&self) -> Option<Point> {
+        if let Some(guard_position) = self.find_guard() {
+            return self.step_guard_from_position(guard_position);
+        }
+        None
+    }
+
+    fn step_guard_from_position(&self, guard_position: Point) -> Option<Point> {
+        let guard = self.get(guard_position)?.as_guard()?;
+        let new_position = self.board.step(guard_position, guard.get_delta())?;
+        if self.is_obstacle(new_position) {
+            return None;
+        }
+        Some(new_position)
+    }
+
+    fn step_if_possible(&mut self, existing_position: Option<Point>) -> Option<Point> {
+        let old_position = existing_position.or_else(|| self.find_guard())?;
+        let new_position = self.step_guard_from_position(old_position)?;
+        let guard = self.get(old_position)?.clone();
+        self.set(old_position, GameBoardCell::Dot(Dot::new()))
+            .ok()?;
+        self.set(new_position, guard).ok()?;
+        Some(new_position)
+    }
+
+    fn step_until_stopped(&mut self) -> usize {
+        let mut guard_position = None;
+        let mut steps = 0;
+        loop {
+            guard_position = self.step_if_possible(guard_position);
+            let Some(position) = guard_position else {
+                break;
+            };
+            self.visited.insert(position);
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Walk the guard forward, turning right at each obstacle, until it leaves the board,
+    /// recording every position it visits; the number of steps taken
+    pub fn count_all_paths_until_stuck(&mut self) -> usize {
+        let mut count = 0;
+        if let Some(start) = self.find_guard() {
+            self.visited.insert(start);
+        }
+        loop {
+            count += self.step_until_stopped();
+            let guard_position = self.find_guard().unwrap();
+            trace!("board after {} steps:\n{}", count, self);
+            // the guard walks off the board rather than turning at its edge
+            if self.is_leaving_board(guard_position) {
+                debug!(%guard_position, steps = count, "guard leaves the board");
+                break;
+            }
+            let guard = self.get(guard_position).and_then(GameBoardCell::as_guard);
+            let guard = guard.expect("Require valid guard").turn_right();
+            debug!(%guard_position, heading = %guard.get_representation(), "guard turns");
+            self.set(guard_position, GameBoardCell::Guard(guard))
+                .expect("Must update guard");
+        }
+        count
+    }
+
+    fn is_leaving_board(&self, guard_position: Point) -> bool {
+        match self.get(guard_position).and_then(GameBoardCell::as_guard) {
+            Some(guard) => self.board.step(guard_position, guard.get_delta()).is_none(),
+            None => false,
+        }
+    }
+
+    fn is_obstacle(&self, position: Point) -> bool {
+        let piece = self.get(position).unwrap();
+        Obstacle::is_obstacle(piece.get_representation())
+    }
+
+    /// Number of distinct positions the guard has visited, its start included
+    pub fn visited_count(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+impl Display for GameBoard {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.board.map(GameBoardCell::get_representation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...\n";
+
+    #[test]
+    fn test_guard_new() {
+        let guard = Guard::new('^');
+        assert!(guard.is_ok());
+    }
+
+    #[test]
+    fn test_guard_new_invalid() {
+        let guard = Guard::new('a');
+        assert!(guard.is_err());
+    }
+
+    #[test]
+    fn test_guard_turn_right() {
+        let guard = Guard::new('^').unwrap();
+        let guard = guard.turn_right();
+        assert_eq!(guard.get_representation(), '>');
+    }
+
+    #[test]
+    fn test_guard_turn_right_twice() {
+        let guard = Guard::new('^').unwrap();
+        let guard = guard.turn_right();
+        let guard = guard.turn_right();
+        assert_eq!(guard.get_representation(), 'v');
+    }
+
+    #[test]
+    fn test_three_rights_make_a_left() {
+        let guard = Guard::new('^').unwrap();
+        let guard = guard.turn_right();
+        let guard = guard.turn_right();
+        let guard = guard.turn_right();
+        assert_eq!(guard.get_representation(), '<');
+    }
+
+    #[test]
+    fn test_all_around_the_world() {
+        let guard = Guard::new('^').unwrap();
+        let guard = guard.turn_right();
+        let guard = guard.turn_right();
+        let guard = guard.turn_right();
+        let guard = guard.turn_right();
+        assert_eq!(guard.get_representation(), '^');
+    }
+
+    #[test]
+    fn test_is_guard() {
+        assert!(Guard::is_guard('^'));
+        assert!(Guard::is_guard('>'));
+        assert!(Guard::is_guard('v'));
+        assert!(Guard::is_guard('<'));
+    }
+
+    #[test]
+    fn test_is_obstacle() {
+        assert!(Obstacle::is_obstacle(OBSTACLE));
+    }
+
+    #[test]
+    fn test_new_obstacle() {
+        let obstacle = Obstacle::new();
+        assert!(Obstacle::is_obstacle(obstacle.get_representation()));
+    }
+
+    #[test]
+    fn test_dot_new() {
+        assert!(Dot::is_dot(DOT));
+    }
+
+    #[test]
+    fn test_new_dot() {
+        let dot = Dot::new();
+        assert!(Dot::is_dot(dot.get_representation()));
+    }
+
+    #[test]
+    fn test_game_board_new() {
+        let game_board = GameBoard::new(10, 10);
+        assert_eq!(
+            game_board.get(Point::new(0, 0)),
+            Some(&GameBoardCell::default())
+        );
+        assert_eq!(
+            game_board.get(Point::new(9, 9)),
+            Some(&GameBoardCell::default())
+        );
+    }
+
+    #[test]
+    fn test_game_board_parse() {
+        let game_board = GameBoard::parse(SAMPLE).unwrap();
+        assert_eq!(game_board.to_string(), SAMPLE);
+        assert_eq!(
+            game_board
+                .get(Point::new(4, 6))
+                .unwrap()
+                .get_representation(),
+            '^'
+        );
+        assert_eq!(
+            game_board
+                .get(Point::new(0, 0))
+                .unwrap()
+                .get_representation(),
+            '.',
+        );
+        assert_eq!(
+            game_board
+                .get(Point::new(0, 1))
+                .unwrap()
+                .get_representation(),
+            '.',
+        );
+        assert_eq!(
+            game_board
+                .get(Point::new(4, 0))
+                .unwrap()
+                .get_representation(),
+            '#',
+        );
+        assert_eq!(
+            game_board
+                .get(Point::new(0, 8))
+                .unwrap()
+                .get_representation(),
+            '#',
+        );
+    }
+
+    #[test]
+    fn test_game_board_find_guard() {
+        let game_board = GameBoard::parse(SAMPLE).unwrap();
+        let guard = game_board.find_guard().unwrap();
+        assert_eq!(guard, Point::new(4, 6));
+    }
+
+    #[test]
+    fn test_step_guard() {
+        let game_board = GameBoard::parse(SAMPLE).unwrap();
+        let guard = game_board.step_guard().unwrap();
+        assert_eq!(guard, Point::new(4, 5));
+    }
+
+    #[test]
+    fn test_step_if_possible() {
+        let mut game_board = GameBoard::parse(SAMPLE).unwrap();
+        let guard = game_board.step_if_possible(None).unwrap();
+        assert_eq!(guard, Point::new(4, 5));
+        let guard_piece = game_board.get(Point::new(4, 5)).unwrap();
+        assert_eq!(guard_piece.get_representation(), '^');
+    }
+
+    #[test]
+    fn test_step_guard_twice() {
+        let game_board = GameBoard::parse(SAMPLE).unwrap();
+        let guard = game_board.step_guard().unwrap();
+        assert_eq!(guard, Point::new(4, 5));
+        let guard = game_board.step_guard().unwrap();
+        assert_eq!(guard, Point::new(4, 5));
+    }
+
+    #[test]
+    fn test_step_until_stuck() {
+        let mut game_board = GameBoard::parse(SAMPLE).unwrap();
+        game_board.step_until_stopped();
+        let guard_position = game_board.find_guard().unwrap();
+        assert_eq!(guard_position, Point::new(4, 1));
+        for y in 2..9 {
+            assert_eq!(
+                game_board
+                    .get(Point::new(4, y))
+                    .unwrap()
+                    .get_representation(),
+                '.'
+            );
+        }
+        assert!(game_board.is_obstacle(Point::new(4, 0)));
+    }
+
+    #[test]
+    fn test_count_all_paths_until_stuck() {
+        let mut game_board = GameBoard::parse(SAMPLE).unwrap();
+        let count = game_board.count_all_paths_until_stuck();
+        assert_eq!(count, 44);
+        assert_eq!(game_board.visited_count(), 41);
+    }
+
+    #[test]
+    fn test_count_all_paths_leaves_board() {
+        // blocked at the edge after the first turn, the guard must still turn again and exit
+        let lines = ".#.\n.^#\n...";
+        let mut game_board = GameBoard::parse(lines).unwrap();
+        let count = game_board.count_all_paths_until_stuck();
+        assert_eq!(count, 1);
+        assert_eq!(game_board.visited_count(), 2);
+    }
+
+    #[test]
+    fn test_visited_includes_start() {
+        let lines = "...\n...\n.^.";
+        let mut game_board = GameBoard::parse(lines).unwrap();
+        let count = game_board.count_all_paths_until_stuck();
+        assert_eq!(count, 2);
+        assert_eq!(game_board.visited_count(), 3);
+    }
+
+    #[test]
+    fn test_game_board_parse_invalid_piece() {
+        let lines = "....\n.^x.\n....";
+        let e = GameBoard::parse(lines).err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "invalid input at line 2, column 3: invalid game piece: `x`"
+        );
+    }
+
+    #[test]
+    fn test_game_board_parse_ragged() {
+        let lines = "....\n.^.\n....";
+        let e = GameBoard::parse(lines).err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "invalid input at line 2, column 4: expected 4 columns, found 3: `.^.`"
+        );
+    }
+
+    #[test]
+    fn test_game_board_parse_guards() {
+        let no_guard = "....";
+        assert!(matches!(
+            GameBoard::parse(no_guard),
+            Err(AocError::Input(_))
+        ));
+        let two_guards = "^..v";
+        let e = GameBoard::parse(two_guards).err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "invalid input at line 1, column 4: expected a single guard: `v`"
+        );
+        assert!(matches!(GameBoard::parse(""), Err(AocError::Input(_))));
+    }
+}
@@ -1 +1,61 @@
-pub mod bitmap;
+//! Advent of Code 2024, day 6: Guard Gallivant: following a guard around the lab
+
+use aoc_core::{aoc, AocError, Layout, Solution};
+
+use crate::gameboard::GameBoard;
+
+/// The lab map and the guard patrolling it
+pub mod gameboard;
+
+/// Number of distinct positions the guard visits
+pub fn count_visited_positions(game_board: &GameBoard) -> usize {
+    let mut game_board = game_board.clone();
+    game_board.count_all_paths_until_stuck();
+    game_board.visited_count()
+}
+
+/// Day 6: Guard Gallivant
+pub struct Day06;
+
+#[aoc]
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const LAYOUT: Layout = Layout::Grid;
+
+    type Input = GameBoard;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        GameBoard::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, AocError> {
+        Ok(count_visited_positions(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::sample_test;
+    use proptest::prelude::*;
+
+    sample_test!(day = 6, part = 1, file = "day6-part1-s1.dat", expect = 41);
+
+    proptest! {
+        #[test]
+        fn prop_parse_never_panics(input in "[.#^>v<x\n]{0,60}") {
+            let _ = GameBoard::parse(&input);
+        }
+
+        // with nothing in the way the guard walks straight up and off the board
+        #[test]
+        fn prop_open_board(width in 1..12usize, height in 1..12usize, x in 0..12usize, y in 0..12usize) {
+            let (x, y) = (x % width, y % height);
+            let mut rows = vec![".".repeat(width); height];
+            rows[y].replace_range(x..=x, "^");
+            let board = GameBoard::parse(&rows.join("\n")).unwrap();
+            prop_assert_eq!(Day06::part1(&board).unwrap(), y + 1);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#![no_main]
+
+use day_01::parse_location_lists;
+use libfuzzer_sys::fuzz_target;
+
+// only the parser: ids anywhere in i64 overflow the solvers' sums, which real lists never do
+fuzz_target!(|input: &str| {
+    let lines: Vec<&str> = input.lines().collect();
+    if let Ok(lists) = parse_location_lists(&lines) {
+        let rendered: Vec<String> = lists
+            .left
+            .iter()
+            .zip(lists.right.iter())
+            .map(|(left, right)| format!("{}   {}", left, right))
+            .collect();
+        let rendered: Vec<&str> = rendered.iter().map(String::as_str).collect();
+        assert_eq!(parse_location_lists(&rendered), Ok(lists));
+    }
+});
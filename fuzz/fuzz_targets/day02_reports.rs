@@ -0,0 +1,18 @@
+#![no_main]
+
+use day_02::parse_reports;
+use libfuzzer_sys::fuzz_target;
+
+// only the parser: levels anywhere in i64 overflow the differences the safety checks take
+fuzz_target!(|input: &str| {
+    if let Ok(reports) = parse_reports(input) {
+        let rendered: Vec<String> = reports
+            .iter()
+            .map(|report| {
+                let levels: Vec<String> = report.iter().map(i64::to_string).collect();
+                levels.join(" ")
+            })
+            .collect();
+        assert_eq!(parse_reports(&rendered.join("\n")), Ok(reports));
+    }
+});
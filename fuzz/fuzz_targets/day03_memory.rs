@@ -0,0 +1,11 @@
+#![no_main]
+
+use day_03::{parse_memory, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(memory) = parse_memory(input) {
+        let _ = part1::scan_forward_and_multiply(&memory);
+        let _ = part2::scan_forward_and_multiply(&memory);
+    }
+});
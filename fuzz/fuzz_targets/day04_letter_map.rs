@@ -0,0 +1,11 @@
+#![no_main]
+
+use day_04::{parse_letter_map, part1, part2};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(map) = parse_letter_map(input) {
+        let _ = part1::search(&map, "XMAS");
+        let _ = part2::search(&map, "MAS");
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day_05::Day05;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(queue) = Day05::parse(input) {
+        let _ = Day05::part1(&queue);
+        let _ = Day05::part2(&queue);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_06::gameboard::GameBoard;
+use libfuzzer_sys::fuzz_target;
+
+// only parsing: a guard walking in a loop never leaves the board
+fuzz_target!(|input: &str| {
+    let _ = GameBoard::parse(input);
+});
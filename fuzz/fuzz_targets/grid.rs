@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_grid::Grid;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(grid) = Grid::parse(input) {
+        if grid.height() > 0 && grid.width() > 0 {
+            assert_eq!(Grid::parse(&grid.to_string()), Ok(grid));
+        }
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use aoc_core::input::{normalize, Layout};
+use aoc_core::parse::{number_rows, sections};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let normalized = normalize(input, Layout::Lines);
+    assert_eq!(
+        normalize(&normalized.text, Layout::Lines).text,
+        normalized.text
+    );
+    let _ = sections(&normalized.text);
+    let _ = number_rows::<i64>(&normalized.text);
+});